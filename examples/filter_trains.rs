@@ -2,7 +2,7 @@
 //!
 //! This example shows how to filter trains based on the route name and then
 //! determine what station the train is currently in route to.
-use amtrak_api::{Client, TrainStatus};
+use amtrak_api::Client;
 use chrono::{Local, Utc};
 
 #[tokio::main]
@@ -10,17 +10,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Client::new()
         .trains()
         .await?
-        .into_iter()
-        .flat_map(|(_, trains)| {
+        .into_values()
+        .flat_map(|trains| {
             trains
                 .into_iter()
                 .filter(|train| train.route_name == "Keystone")
         })
         .map(|train| {
             let enroute_information = train
-                .stations
-                .iter()
-                .find(|station| station.status == TrainStatus::Enroute)
+                .next_station()
                 .map(|station| (station.name.clone(), station.arrival));
 
             (train, enroute_information)
@@ -18,7 +18,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(trains) => match trains.len() {
             1 => {
                 let phl_station = trains
-                    .get(0)
+                    .first()
                     .unwrap()
                     .stations
                     .iter()
@@ -35,7 +35,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         TrainStatus::Departed => {
                             println!("Train has departed Philadelphia station")
                         }
-                        TrainStatus::Unknown => println!("The train status is unknown"),
+                        _ => println!("The train status is unknown"),
                     },
                     None => println!(
                         "Philadelphia station was not found in the \"{}\" route",
@@ -0,0 +1,211 @@
+//! Blocking Amtrak API Client
+//!
+//! The blocking client mirrors the endpoints of the asynchronous [`Client`]
+//! for callers that are not running inside an async runtime (batch jobs,
+//! scripts, etc). It returns the same response and error types.
+//!
+//! Note: The blocking client drives the asynchronous client on its own single
+//! threaded runtime. It must not be used from within an async runtime since
+//! blocking the executor thread will panic.
+//!
+//! [`Client`]: crate::Client
+
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    client::{self, Result},
+    drift::DriftReport,
+    errors,
+    lenient::Partial,
+    responses,
+    train_id::TrainIdentifier,
+    transport::TransportError,
+};
+
+/// A blocking client instance
+///
+/// Every clone of the client shares the same runtime, transport, retry policy
+/// and rate limiter.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::blocking::Client;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     Client::new()
+///         .stations()?
+///         .values()
+///         .filter(|station| station.state == "PA")
+///         .for_each(|station| {
+///             println!("Station \"{}\" is in PA", station.name);
+///         });
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: client::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a new instance with the default Amtrak API endpoint
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the underlying HTTP client or runtime cannot be
+    /// initialized. Use [`ClientBuilder::build_blocking`] to handle this error
+    /// instead.
+    ///
+    /// [`ClientBuilder::build_blocking`]: crate::ClientBuilder::build_blocking
+    #[cfg(feature = "reqwest")]
+    pub fn new() -> Self {
+        Self::from_async(client::Client::new()).expect("Unable to build the runtime")
+    }
+
+    /// Creates a new instance with the provided Amtrak endpoint
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base url of the endpoint that this client will query
+    ///   when making API calls.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the underlying HTTP client or runtime cannot be
+    /// initialized. Use [`ClientBuilder::build_blocking`] to handle this error
+    /// instead.
+    ///
+    /// [`ClientBuilder::build_blocking`]: crate::ClientBuilder::build_blocking
+    #[cfg(feature = "reqwest")]
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self::from_async(client::Client::with_base_url(base_url))
+            .expect("Unable to build the runtime")
+    }
+
+    /// Creates a blocking client from an already configured asynchronous
+    /// [`Client`]
+    ///
+    /// This function returns [`Error::ClientBuildFailed`] if the runtime
+    /// cannot be initialized.
+    ///
+    /// [`Client`]: crate::Client
+    /// [`Error::ClientBuildFailed`]: errors::Error::ClientBuildFailed
+    pub fn from_async(client: client::Client) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| errors::Error::ClientBuildFailed(TransportError::other(err)))?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns all trains being tracked by Amtrak
+    ///
+    /// This is the blocking version of [`Client::trains`].
+    ///
+    /// [`Client::trains`]: crate::Client::trains
+    pub fn trains(&self) -> Result<responses::TrainResponse> {
+        self.runtime.block_on(self.inner.trains())
+    }
+
+    /// Returns all trains being tracked by Amtrak, skipping the trains that
+    /// cannot be deserialized
+    ///
+    /// This is the blocking version of [`Client::trains_partial`].
+    ///
+    /// [`Client::trains_partial`]: crate::Client::trains_partial
+    pub fn trains_partial(&self) -> Result<Partial<responses::TrainResponse>> {
+        self.runtime.block_on(self.inner.trains_partial())
+    }
+
+    /// Returns all trains being tracked by Amtrak along with a report of the
+    /// schema drift of the response
+    ///
+    /// This is the blocking version of [`Client::trains_with_drift`].
+    ///
+    /// [`Client::trains_with_drift`]: crate::Client::trains_with_drift
+    pub fn trains_with_drift(&self) -> Result<(responses::TrainResponse, DriftReport)> {
+        self.runtime.block_on(self.inner.trains_with_drift())
+    }
+
+    /// Returns all trains being tracked that belong to the provided network
+    ///
+    /// This is the blocking version of [`Client::trains_by_provider`].
+    ///
+    /// [`Client::trains_by_provider`]: crate::Client::trains_by_provider
+    pub fn trains_by_provider<P>(&self, provider: P) -> Result<responses::TrainResponse>
+    where
+        P: Into<responses::Provider>,
+    {
+        self.runtime
+            .block_on(self.inner.trains_by_provider(provider))
+    }
+
+    /// Returns the specified train(s) being tracked by Amtrak
+    ///
+    /// This is the blocking version of [`Client::train`].
+    ///
+    /// [`Client::train`]: crate::Client::train
+    pub fn train<T>(&self, train_identifier: T) -> Result<responses::TrainResponse>
+    where
+        T: Into<TrainIdentifier>,
+    {
+        self.runtime.block_on(self.inner.train(train_identifier))
+    }
+
+    /// Returns all the stations in the Amtrak network
+    ///
+    /// This is the blocking version of [`Client::stations`].
+    ///
+    /// [`Client::stations`]: crate::Client::stations
+    pub fn stations(&self) -> Result<responses::StationResponse> {
+        self.runtime.block_on(self.inner.stations())
+    }
+
+    /// Returns all the stations in the Amtrak network, skipping the stations
+    /// that cannot be deserialized
+    ///
+    /// This is the blocking version of [`Client::stations_partial`].
+    ///
+    /// [`Client::stations_partial`]: crate::Client::stations_partial
+    pub fn stations_partial(&self) -> Result<Partial<responses::StationResponse>> {
+        self.runtime.block_on(self.inner.stations_partial())
+    }
+
+    /// Returns all the stations in the Amtrak network along with a report of
+    /// the schema drift of the response
+    ///
+    /// This is the blocking version of [`Client::stations_with_drift`].
+    ///
+    /// [`Client::stations_with_drift`]: crate::Client::stations_with_drift
+    pub fn stations_with_drift(&self) -> Result<(responses::StationResponse, DriftReport)> {
+        self.runtime.block_on(self.inner.stations_with_drift())
+    }
+
+    /// Returns the specified station in the Amtrak network
+    ///
+    /// This is the blocking version of [`Client::station`].
+    ///
+    /// [`Client::station`]: crate::Client::station
+    pub fn station<S>(&self, station_code: S) -> Result<responses::StationResponse>
+    where
+        S: AsRef<str>,
+    {
+        self.runtime.block_on(self.inner.station(station_code))
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,322 @@
+//! Clean Serialization
+//!
+//! The response types serialize back into the same JSON shape returned by the
+//! Amtrak API (`camelCase` keys such as `routeName` and `schArr`). The
+//! [`Clean`] wrapper instead serializes them using the `snake_case` Rust field
+//! names (`route_name`, `schedule_arrival`, etc).
+//!
+//! Serializing normalizes the fields the Amtrak API provides in more than one
+//! form: a [`zip`] provided as an integer is serialized as a string and a
+//! missing [`arrival`] or [`departure`] is serialized as `null`.
+//!
+//! [`zip`]: crate::Station::zip
+//! [`arrival`]: crate::TrainStation::arrival
+//! [`departure`]: crate::TrainStation::departure
+
+use chrono::{DateTime, FixedOffset};
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize, Serializer,
+};
+
+use crate::{
+    responses::{
+        Heading, Provider, Station, StationResponse, Train, TrainResponse, TrainState,
+        TrainStation, TrainStatus,
+    },
+    timeliness::TimelinessComment,
+    train_id::TrainId,
+};
+
+/// Serializes the wrapped value using the Rust field names
+///
+/// The wrapper can be used with a [`Train`], [`TrainStation`], [`Station`],
+/// [`TrainResponse`] or [`StationResponse`] reference.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use amtrak_api::{Clean, Station};
+///
+/// let stations: HashMap<String, Station> = serde_json::from_str(
+///     r#"{
+///         "PHL": {
+///             "name": "Philadelphia 30th Street",
+///             "code": "PHL",
+///             "tz": "America/New_York",
+///             "lat": 39.955864,
+///             "lon": -75.181887,
+///             "address1": "2955 Market Street",
+///             "address2": " ",
+///             "city": "Philadelphia",
+///             "state": "PA",
+///             "zip": 19104,
+///             "trains": []
+///         }
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let json = serde_json::to_value(Clean(&stations)).unwrap();
+/// assert_eq!(json["PHL"]["zip"], "19104");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Clean<T>(pub T);
+
+// The `Clean*` mirror structs below are built by destructuring the data
+// models without `..`, so adding a field to a data model fails to compile until
+// it is added to its mirror as well.
+
+#[derive(Serialize)]
+struct CleanTrain<'a> {
+    route_name: &'a String,
+    train_num: &'a String,
+    train_id: &'a TrainId,
+    lat: &'a f64,
+    lon: &'a f64,
+    train_timely: &'a TimelinessComment,
+    stations: Clean<&'a [TrainStation]>,
+    heading: &'a Heading,
+    event_code: &'a String,
+    event_tz: &'a Option<String>,
+    event_name: &'a Option<String>,
+    origin_code: &'a String,
+    origin_tz: &'a Option<String>,
+    origin_name: &'a String,
+    destination_code: &'a String,
+    destination_tz: &'a Option<String>,
+    destination_name: &'a String,
+    train_state: &'a TrainState,
+    velocity: &'a f32,
+    status_message: &'a String,
+    created_at: &'a DateTime<FixedOffset>,
+    updated_at: &'a DateTime<FixedOffset>,
+    last_value: &'a DateTime<FixedOffset>,
+    object_id: &'a Option<u32>,
+    provider: &'a Provider,
+}
+
+impl<'a> From<&'a Train> for CleanTrain<'a> {
+    fn from(train: &'a Train) -> Self {
+        let Train {
+            route_name,
+            train_num,
+            train_id,
+            lat,
+            lon,
+            train_timely,
+            stations,
+            heading,
+            event_code,
+            event_tz,
+            event_name,
+            origin_code,
+            origin_tz,
+            origin_name,
+            destination_code,
+            destination_tz,
+            destination_name,
+            train_state,
+            velocity,
+            status_message,
+            created_at,
+            updated_at,
+            last_value,
+            object_id,
+            provider,
+        } = train;
+
+        Self {
+            route_name,
+            train_num,
+            train_id,
+            lat,
+            lon,
+            train_timely,
+            stations: Clean(stations.as_slice()),
+            heading,
+            event_code,
+            event_tz,
+            event_name,
+            origin_code,
+            origin_tz,
+            origin_name,
+            destination_code,
+            destination_tz,
+            destination_name,
+            train_state,
+            velocity,
+            status_message,
+            created_at,
+            updated_at,
+            last_value,
+            object_id,
+            provider,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CleanTrainStation<'a> {
+    name: &'a String,
+    code: &'a String,
+    tz: &'a Option<String>,
+    bus: &'a bool,
+    schedule_arrival: &'a DateTime<FixedOffset>,
+    schedule_departure: &'a DateTime<FixedOffset>,
+    arrival: &'a Option<DateTime<FixedOffset>>,
+    departure: &'a Option<DateTime<FixedOffset>>,
+    arrival_comment: &'a TimelinessComment,
+    departure_comment: &'a TimelinessComment,
+    status: &'a TrainStatus,
+}
+
+impl<'a> From<&'a TrainStation> for CleanTrainStation<'a> {
+    fn from(station: &'a TrainStation) -> Self {
+        let TrainStation {
+            name,
+            code,
+            tz,
+            bus,
+            schedule_arrival,
+            schedule_departure,
+            arrival,
+            departure,
+            arrival_comment,
+            departure_comment,
+            status,
+        } = station;
+
+        Self {
+            name,
+            code,
+            tz,
+            bus,
+            schedule_arrival,
+            schedule_departure,
+            arrival,
+            departure,
+            arrival_comment,
+            departure_comment,
+            status,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CleanStation<'a> {
+    name: &'a String,
+    code: &'a String,
+    tz: &'a String,
+    lat: &'a f64,
+    lon: &'a f64,
+    address1: &'a String,
+    address2: &'a String,
+    city: &'a String,
+    state: &'a String,
+    zip: &'a String,
+    trains: &'a Vec<String>,
+}
+
+impl<'a> From<&'a Station> for CleanStation<'a> {
+    fn from(station: &'a Station) -> Self {
+        let Station {
+            name,
+            code,
+            tz,
+            lat,
+            lon,
+            address1,
+            address2,
+            city,
+            state,
+            zip,
+            trains,
+        } = station;
+
+        Self {
+            name,
+            code,
+            tz,
+            lat,
+            lon,
+            address1,
+            address2,
+            city,
+            state,
+            zip,
+            trains,
+        }
+    }
+}
+
+impl Serialize for Clean<&Train> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        CleanTrain::from(self.0).serialize(serializer)
+    }
+}
+
+impl Serialize for Clean<&TrainStation> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        CleanTrainStation::from(self.0).serialize(serializer)
+    }
+}
+
+impl Serialize for Clean<&Station> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        CleanStation::from(self.0).serialize(serializer)
+    }
+}
+
+impl<'a, T> Serialize for Clean<&'a [T]>
+where
+    Clean<&'a T>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for item in self.0 {
+            seq.serialize_element(&Clean(item))?;
+        }
+        seq.end()
+    }
+}
+
+impl Serialize for Clean<&TrainResponse> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (train_num, trains) in self.0 {
+            map.serialize_entry(train_num, &Clean(trains.as_slice()))?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Clean<&StationResponse> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (code, station) in self.0 {
+            map.serialize_entry(code, &Clean(station))?;
+        }
+        map.end()
+    }
+}
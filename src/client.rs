@@ -3,25 +3,58 @@
 //! The client allows the user to call the various different endpoints provided
 //! by the API.
 
-use crate::{errors, responses};
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use http::header::RETRY_AFTER;
+#[cfg(feature = "reqwest")]
+use reqwest::{header::HeaderMap, Proxy};
+
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use crate::{
+    drift::DriftReport,
+    errors,
+    lenient::{self, Partial, SkipHandler, SkippedEntry},
+    rate_limit::{RateLimit, RateLimiter},
+    responses,
+    retry::RetryPolicy,
+    train_id::TrainIdentifier,
+    transport::{Transport, TransportError},
+};
 
 /// Default endpoint for Amtrak API
 const BASE_API_URL: &str = "https://api-v3.amtraker.com/v3";
 
-pub type Result<T> = std::result::Result<T, errors::Error>;
+/// Default user agent sent with every request
+#[cfg(feature = "reqwest")]
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Maximum number of bytes of an error response body that will be kept
+const MAX_ERROR_BODY_LEN: usize = 512;
 
-#[cfg(feature = "serde_debugging")]
-pub type DebuggingResult<T> = std::result::Result<T, errors::DebuggingError>;
+/// A `Result` alias where the `Err` case is [`Error`]
+///
+/// [`Error`]: errors::Error
+pub type Result<T> = std::result::Result<T, errors::Error>;
 
 /// A client instance
 ///
-/// Note: This does not represent an active connection. Connections are
-/// established when making an endpoint call and are not persistent after.
+/// The client owns a [`Transport`] (by default a pooled HTTP client).
+/// Connections are kept alive between endpoint calls and the transport is
+/// shared by every clone of the client, so it is cheap to clone a single client
+/// into multiple tasks.
 #[derive(Debug, Clone)]
 pub struct Client {
     base_url: String,
+    transport: Arc<dyn Transport>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    deserialize_diagnostics: bool,
+    lenient: Option<SkipHandler>,
 }
 
+#[cfg(feature = "reqwest")]
 impl Default for Client {
     fn default() -> Self {
         Self::new()
@@ -31,6 +64,12 @@ impl Default for Client {
 impl Client {
     /// Creates a new instance with the default Amtrak API endpoint
     ///
+    /// # Panics
+    ///
+    /// This function panics if the underlying HTTP client cannot be
+    /// initialized (for example if the TLS backend fails to load). Use
+    /// [`Client::builder`] to handle this error instead.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -42,10 +81,11 @@ impl Client {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "reqwest")]
     pub fn new() -> Self {
-        Self {
-            base_url: BASE_API_URL.to_string(),
-        }
+        ClientBuilder::new()
+            .build()
+            .expect("Unable to build the HTTP client")
     }
 
     /// Creates a new instance with the provided Amtrak endpoint
@@ -58,6 +98,11 @@ impl Client {
     /// * `base_url` - The base url of the endpoint that this client will query
     ///   when making API calls.
     ///
+    /// # Panics
+    ///
+    /// This function panics if the underlying HTTP client cannot be
+    /// initialized. Use [`Client::builder`] to handle this error instead.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -69,10 +114,35 @@ impl Client {
     ///     Ok(())
     /// }
     /// ```
+    #[cfg(feature = "reqwest")]
     pub fn with_base_url(base_url: &str) -> Self {
-        Self {
-            base_url: base_url.to_string(),
-        }
+        ClientBuilder::new()
+            .base_url(base_url)
+            .build()
+            .expect("Unable to build the HTTP client")
+    }
+
+    /// Creates a [`ClientBuilder`] to configure a client
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use amtrak_api::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder()
+    ///         .connect_timeout(Duration::from_secs(5))
+    ///         .read_timeout(Duration::from_secs(10))
+    ///         .user_agent("departure-board/1.0")
+    ///         .build()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
     }
 
     /// Returns all trains being tracked by Amtrak
@@ -94,8 +164,8 @@ impl Client {
     ///     Client::new()
     ///         .trains()
     ///         .await?
-    ///         .into_iter()
-    ///         .flat_map(|(_, trains)| {
+    ///         .into_values()
+    ///         .flat_map(|trains| {
     ///             trains
     ///                 .into_iter()
     ///                 .filter(|train| train.route_name == "Keystone")
@@ -143,48 +213,97 @@ impl Client {
     pub async fn trains(&self) -> Result<responses::TrainResponse> {
         let url = format!("{}/trains", self.base_url);
 
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .json::<responses::TrainResponseWrapper>()
-            .await?;
+        let bytes = self.get(&url).await?;
+        self.deserialize_trains(&bytes)
+    }
+
+    /// Returns all trains being tracked by Amtrak, skipping the trains that
+    /// cannot be deserialized
+    ///
+    /// This function calls into the `/trains` endpoint.
+    ///
+    /// Unlike [`trains`], a malformed train does not fail the entire response.
+    /// Instead it is reported in the [`skipped`] list along with the path and
+    /// a snippet of the offending field. The response itself still fails if it
+    /// is not valid JSON or is not a map of trains.
+    ///
+    /// [`trains`]: Self::trains
+    /// [`skipped`]: Partial::skipped
+    pub async fn trains_partial(&self) -> Result<Partial<responses::TrainResponse>> {
+        let url = format!("{}/trains", self.base_url);
 
-        Ok(response.0)
+        let bytes = self.get(&url).await?;
+        self.deserialize_trains_partial(&bytes)
     }
 
-    /// Same as [`trains`] but using [`serde_path_to_error`] as the deserialize adapter
+    /// Returns all trains being tracked by Amtrak along with a report of the
+    /// schema drift of the response
     ///
-    /// Note: This function will will return [`Error::Other`] instead of [`Error::DeserializeFailed`]
-    /// when a deserialization error occurs. The reason for this is that we want to log the offending
-    /// JSON when a deserialization error does occur and will use the [`anyhow`] crate to include the
-    /// JSON and failed field path to make debugging a lot easier.
+    /// This function calls into the `/trains` endpoint.
     ///
-    /// [`trains`]: Client::trains
-    /// [`Error::Other`]: errors::Error::Other
-    /// [`Error::DeserializeFailed`]: errors::Error::DeserializeFailed
-    #[cfg(feature = "serde_debugging")]
-    pub async fn trains_with_debugging(&self) -> DebuggingResult<responses::TrainResponse> {
+    /// The [`DriftReport`] lists the fields of the response that are unknown
+    /// to the [`Train`] and [`TrainStation`] data models as well as the
+    /// expected fields that are missing or `null`. The trains are deserialized
+    /// exactly like [`trains`] does.
+    ///
+    /// [`Train`]: responses::Train
+    /// [`TrainStation`]: responses::TrainStation
+    /// [`trains`]: Self::trains
+    pub async fn trains_with_drift(&self) -> Result<(responses::TrainResponse, DriftReport)> {
         let url = format!("{}/trains", self.base_url);
 
-        let bytes = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .bytes()
-            .await?;
+        let bytes = self.get(&url).await?;
+        let value: serde_json::Value = self.deserialize(&bytes)?;
 
-        let response: responses::TrainResponseWrapper = serde_path_to_error::deserialize(
-            &mut serde_json::Deserializer::from_slice(bytes.as_ref()),
-        )
-        .map_err(|err| errors::DebuggingError::DeserializeFailed {
-            error: err,
-            response: std::str::from_utf8(bytes.as_ref())
-                .unwrap_or("Failed to convert bytes to string")
-                .to_string(),
-        })?;
+        Ok((
+            self.deserialize_trains(&bytes)?,
+            DriftReport::trains(&value),
+        ))
+    }
 
-        Ok(response.0)
+    /// Returns all trains being tracked that belong to the provided network
+    ///
+    /// This function calls into the `/trains` endpoint and only keeps the
+    /// trains whose [`provider`] matches. Entries of the [`TrainResponse`]
+    /// without any matching train are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The network of the trains to return (see [`Provider`])
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use amtrak_api::{Client, Provider};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     for train in client.trains_by_provider(Provider::Via).await?.values().flatten() {
+    ///         println!("Via train {} is heading to {}", train.train_id, train.destination_name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`provider`]: responses::Train::provider
+    /// [`Provider`]: responses::Provider
+    /// [`TrainResponse`]: responses::TrainResponse
+    pub async fn trains_by_provider<P>(&self, provider: P) -> Result<responses::TrainResponse>
+    where
+        P: Into<responses::Provider>,
+    {
+        let provider = provider.into();
+        let mut response = self.trains().await?;
+
+        response.retain(|_, trains| {
+            trains.retain(|train| train.provider == provider);
+            !trains.is_empty()
+        });
+
+        Ok(response)
     }
 
     /// Returns the specified train(s) being tracked by Amtrak
@@ -198,7 +317,8 @@ impl Client {
     /// # Arguments
     ///
     /// * `train_identifier` - Can either be the [`train_id`] or the
-    ///   [`train_num`] of the train the caller wants to query.
+    ///   [`train_num`] of the train the caller wants to query. See
+    ///   [`TrainIdentifier`] for how strings are interpreted.
     ///
     /// # Example
     ///
@@ -219,7 +339,7 @@ impl Client {
     ///         Some(trains) => match trains.len() {
     ///             1 => {
     ///                 let phl_station = trains
-    ///                     .get(0)
+    ///                     .first()
     ///                     .unwrap()
     ///                     .stations
     ///                     .iter()
@@ -236,7 +356,7 @@ impl Client {
     ///                         TrainStatus::Departed => {
     ///                             println!("Train has departed Philadelphia station")
     ///                         }
-    ///                         TrainStatus::Unknown => println!("The train status is unknown"),
+    ///                         _ => println!("The train status is unknown"),
     ///                     },
     ///                     None => println!(
     ///                         "Philadelphia station was not found in the \"{}\" route",
@@ -260,60 +380,14 @@ impl Client {
     /// [`TrainResponse`]: responses::TrainResponse
     /// [`train_id`]: responses::Train::train_id
     /// [`train_num`]: responses::Train::train_num
-    pub async fn train<S>(&self, train_identifier: S) -> Result<responses::TrainResponse>
-    where
-        S: AsRef<str>,
-    {
-        let url = format!("{}/trains/{}", self.base_url, train_identifier.as_ref());
-
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .json::<responses::TrainResponseWrapper>()
-            .await?;
-
-        Ok(response.0)
-    }
-
-    /// Same as [`train`] but using [`serde_path_to_error`] as the deserialize adapter
-    ///
-    /// Note: This function will will return [`Error::Other`] instead of [`Error::DeserializeFailed`]
-    /// when a deserialization error occurs. The reason for this is that we want to log the offending
-    /// JSON when a deserialization error does occur and will use the [`anyhow`] crate to include the
-    /// JSON and failed field path to make debugging a lot easier.
-    ///
-    /// [`train`]: Client::train
-    /// [`Error::Other`]: errors::Error::Other
-    /// [`Error::DeserializeFailed`]: errors::Error::DeserializeFailed
-    #[cfg(feature = "serde_debugging")]
-    pub async fn train_with_debugging<S>(
-        &self,
-        train_identifier: S,
-    ) -> DebuggingResult<responses::TrainResponse>
+    pub async fn train<T>(&self, train_identifier: T) -> Result<responses::TrainResponse>
     where
-        S: AsRef<str>,
+        T: Into<TrainIdentifier>,
     {
-        let url = format!("{}/trains/{}", self.base_url, train_identifier.as_ref());
-
-        let bytes = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .bytes()
-            .await?;
-
-        let response: responses::TrainResponseWrapper = serde_path_to_error::deserialize(
-            &mut serde_json::Deserializer::from_slice(bytes.as_ref()),
-        )
-        .map_err(|err| errors::DebuggingError::DeserializeFailed {
-            error: err,
-            response: std::str::from_utf8(bytes.as_ref())
-                .unwrap_or("Failed to convert bytes to string")
-                .to_string(),
-        })?;
+        let url = format!("{}/trains/{}", self.base_url, train_identifier.into());
 
-        Ok(response.0)
+        let bytes = self.get(&url).await?;
+        self.deserialize_trains(&bytes)
     }
 
     /// Returns all the stations in the Amtrak network
@@ -348,48 +422,51 @@ impl Client {
     pub async fn stations(&self) -> Result<responses::StationResponse> {
         let url = format!("{}/stations", self.base_url);
 
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .json::<responses::StationResponseWrapper>()
-            .await?;
-
-        Ok(response.0)
+        let bytes = self.get(&url).await?;
+        self.deserialize_stations(&bytes)
     }
 
-    /// Same as [`stations`] but using [`serde_path_to_error`] as the deserialize adapter
+    /// Returns all the stations in the Amtrak network, skipping the stations
+    /// that cannot be deserialized
     ///
-    /// Note: This function will will return [`Error::Other`] instead of [`Error::DeserializeFailed`]
-    /// when a deserialization error occurs. The reason for this is that we want to log the offending
-    /// JSON when a deserialization error does occur and will use the [`anyhow`] crate to include the
-    /// JSON and failed field path to make debugging a lot easier.
+    /// This function calls into the `/stations` endpoint.
     ///
-    /// [`stations`]: Client::stations
-    /// [`Error::Other`]: errors::Error::Other
-    /// [`Error::DeserializeFailed`]: errors::Error::DeserializeFailed
-    #[cfg(feature = "serde_debugging")]
-    pub async fn stations_with_debugging(&self) -> DebuggingResult<responses::StationResponse> {
+    /// Unlike [`stations`], a malformed station does not fail the entire
+    /// response. Instead it is reported in the [`skipped`] list along with the
+    /// path and a snippet of the offending field. The response itself still
+    /// fails if it is not valid JSON or is not a map of stations.
+    ///
+    /// [`stations`]: Self::stations
+    /// [`skipped`]: Partial::skipped
+    pub async fn stations_partial(&self) -> Result<Partial<responses::StationResponse>> {
         let url = format!("{}/stations", self.base_url);
 
-        let bytes = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .bytes()
-            .await?;
+        let bytes = self.get(&url).await?;
+        self.deserialize_stations_partial(&bytes)
+    }
+
+    /// Returns all the stations in the Amtrak network along with a report of
+    /// the schema drift of the response
+    ///
+    /// This function calls into the `/stations` endpoint.
+    ///
+    /// The [`DriftReport`] lists the fields of the response that are unknown
+    /// to the [`Station`] data model as well as the expected fields that are
+    /// missing or `null`. The stations are deserialized exactly like
+    /// [`stations`] does.
+    ///
+    /// [`Station`]: responses::Station
+    /// [`stations`]: Self::stations
+    pub async fn stations_with_drift(&self) -> Result<(responses::StationResponse, DriftReport)> {
+        let url = format!("{}/stations", self.base_url);
 
-        let response: responses::StationResponseWrapper = serde_path_to_error::deserialize(
-            &mut serde_json::Deserializer::from_slice(bytes.as_ref()),
-        )
-        .map_err(|err| errors::DebuggingError::DeserializeFailed {
-            error: err,
-            response: std::str::from_utf8(bytes.as_ref())
-                .unwrap_or("Failed to convert bytes to string")
-                .to_string(),
-        })?;
+        let bytes = self.get(&url).await?;
+        let value: serde_json::Value = self.deserialize(&bytes)?;
 
-        Ok(response.0)
+        Ok((
+            self.deserialize_stations(&bytes)?,
+            DriftReport::stations(&value),
+        ))
     }
 
     /// Returns the specified station in the Amtrak network
@@ -437,53 +514,500 @@ impl Client {
     {
         let url = format!("{}/stations/{}", self.base_url, station_code.as_ref());
 
-        let response = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .json::<responses::StationResponseWrapper>()
-            .await?;
+        let bytes = self.get(&url).await?;
+        self.deserialize_stations(&bytes)
+    }
 
-        Ok(response.0)
+    /// Fetches the body of the provided url, retrying transient failures
+    /// according to the configured [`RetryPolicy`]
+    async fn get(&self, url: &str) -> std::result::Result<Vec<u8>, errors::RequestError> {
+        let mut attempt = 1;
+
+        loop {
+            let error = match self.try_get(url).await {
+                Ok(bytes) => return Ok(bytes),
+                Err(error) => error,
+            };
+
+            match self
+                .retry_policy
+                .as_ref()
+                .and_then(|policy| policy.retry_delay(attempt, &error))
+            {
+                Some(delay) => {
+                    self.transport.sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return Err(error),
+            }
+        }
     }
 
-    /// Same as [`station`] but using [`serde_path_to_error`] as the deserialize adapter
+    /// Deserializes the body of a response
     ///
-    /// Note: This function will will return [`Error::Other`] instead of [`Error::DeserializeFailed`]
-    /// when a deserialization error occurs. The reason for this is that we want to log the offending
-    /// JSON when a deserialization error does occur and will use the [`anyhow`] crate to include the
-    /// JSON and failed field path to make debugging a lot easier.
+    /// When deserialize diagnostics are enabled, [`serde_path_to_error`] is used
+    /// as the deserialize adapter so that the path of the offending field and
+    /// the response body are included in the error.
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+
+        let value = if self.deserialize_diagnostics {
+            serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+                errors::Error::DeserializeFailed {
+                    path: Some(err.path().to_string()),
+                    response: Some(String::from_utf8_lossy(bytes).into_owned()),
+                    error: err.into_inner(),
+                }
+            })?
+        } else {
+            T::deserialize(&mut deserializer)?
+        };
+
+        deserializer.end()?;
+        Ok(value)
+    }
+
+    /// Deserializes the body of a `/trains` or `/trains/{:train_id}` response
+    ///
+    /// In lenient mode every train that cannot be deserialized is skipped and
+    /// reported to the [`SkipHandler`].
+    fn deserialize_trains(&self, bytes: &[u8]) -> Result<responses::TrainResponse> {
+        match &self.lenient {
+            Some(handler) => Ok(handler.report(self.deserialize_trains_partial(bytes)?)),
+            None => {
+                let response: responses::TrainResponseWrapper = self.deserialize(bytes)?;
+                Ok(response.0)
+            }
+        }
+    }
+
+    /// Deserializes the body of a `/trains` or `/trains/{:train_id}` response,
+    /// skipping every train that cannot be deserialized
+    fn deserialize_trains_partial(
+        &self,
+        bytes: &[u8],
+    ) -> Result<Partial<responses::TrainResponse>> {
+        let value: serde_json::Value = self.deserialize(bytes)?;
+
+        match lenient::trains(value) {
+            Some(partial) => Ok(partial),
+            None => {
+                // Not salvageable, return the same error as the strict parser
+                let response: responses::TrainResponseWrapper = self.deserialize(bytes)?;
+                Ok(Partial {
+                    value: response.0,
+                    skipped: Vec::new(),
+                })
+            }
+        }
+    }
+
+    /// Deserializes the body of a `/stations` or `/stations/{:station_code}`
+    /// response
+    ///
+    /// In lenient mode every station that cannot be deserialized is skipped and
+    /// reported to the [`SkipHandler`].
+    fn deserialize_stations(&self, bytes: &[u8]) -> Result<responses::StationResponse> {
+        match &self.lenient {
+            Some(handler) => Ok(handler.report(self.deserialize_stations_partial(bytes)?)),
+            None => {
+                let response: responses::StationResponseWrapper = self.deserialize(bytes)?;
+                Ok(response.0)
+            }
+        }
+    }
+
+    /// Deserializes the body of a `/stations` or `/stations/{:station_code}`
+    /// response, skipping every station that cannot be deserialized
+    fn deserialize_stations_partial(
+        &self,
+        bytes: &[u8],
+    ) -> Result<Partial<responses::StationResponse>> {
+        let value: serde_json::Value = self.deserialize(bytes)?;
+
+        match lenient::stations(value) {
+            Some(partial) => Ok(partial),
+            None => {
+                // Not salvageable, return the same error as the strict parser
+                let response: responses::StationResponseWrapper = self.deserialize(bytes)?;
+                Ok(Partial {
+                    value: response.0,
+                    skipped: Vec::new(),
+                })
+            }
+        }
+    }
+
+    /// Makes a single attempt at fetching the body of the provided url
+    async fn try_get(&self, url: &str) -> std::result::Result<Vec<u8>, errors::RequestError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            let delay = rate_limiter
+                .acquire()
+                .map_err(errors::RequestError::RateLimited)?;
+            if !delay.is_zero() {
+                self.transport.sleep(delay).await;
+            }
+        }
+
+        let response = self.transport.get(url).await?;
+        Ok(check_status(response)?.into_body())
+    }
+}
+
+/// Checks the status code of the response returned by the API
+///
+/// Successful (2xx) responses are returned untouched. Any other response is
+/// converted into an [`ErrorResponse`] containing the status code, the
+/// `Retry-After` header (if any) and the start of the body.
+///
+/// [`ErrorResponse`]: errors::ErrorResponse
+fn check_status(
+    response: http::Response<Vec<u8>>,
+) -> std::result::Result<http::Response<Vec<u8>>, errors::ErrorResponse> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after);
+
+    let mut body = String::from_utf8_lossy(response.body()).into_owned();
+    if body.len() > MAX_ERROR_BODY_LEN {
+        let mut end = MAX_ERROR_BODY_LEN;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        body.truncate(end);
+    }
+
+    Err(errors::ErrorResponse {
+        status: status.as_u16(),
+        retry_after,
+        body,
+    })
+}
+
+/// Parses the value of a `Retry-After` header
+///
+/// The header can either be a number of seconds (`120`) or a HTTP date (`Wed,
+/// 21 Oct 2015 07:28:00 GMT`). Dates in the past are treated as no delay.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        date.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// A builder used to configure a [`Client`]
+///
+/// By default the builder creates a single pooled [`reqwest::Client`] which is
+/// then shared by every endpoint call (and every clone) of the resulting
+/// [`Client`]. A custom [`Transport`] can be provided instead using
+/// [`transport`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use amtrak_api::ClientBuilder;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = ClientBuilder::new()
+///         .base_url("https://api-v3.amtraker.com/v3")
+///         .timeout(Duration::from_secs(30))
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [`transport`]: Self::transport
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "reqwest")]
+    http_client: Option<reqwest::Client>,
+    #[cfg(feature = "reqwest")]
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    read_timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    user_agent: Option<String>,
+    #[cfg(feature = "reqwest")]
+    default_headers: HeaderMap,
+    #[cfg(feature = "reqwest")]
+    proxies: Vec<Proxy>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit: Option<RateLimit>,
+    deserialize_diagnostics: Option<bool>,
+    lenient: Option<SkipHandler>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder using the default Amtrak API endpoint
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base url of the endpoint that the client will query
+    ///
+    /// Defaults to `https://api-v3.amtraker.com/v3`.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Uses the provided [`Transport`] to send every request
+    ///
+    /// Note: When a transport is provided, the [`http_client`],
+    /// [`connect_timeout`], [`read_timeout`], [`timeout`], [`user_agent`],
+    /// [`default_headers`] and [`proxy`] options are ignored since they only
+    /// apply to the default [`ReqwestTransport`].
+    ///
+    /// [`http_client`]: Self::http_client
+    /// [`connect_timeout`]: Self::connect_timeout
+    /// [`read_timeout`]: Self::read_timeout
+    /// [`timeout`]: Self::timeout
+    /// [`user_agent`]: Self::user_agent
+    /// [`default_headers`]: Self::default_headers
+    /// [`proxy`]: Self::proxy
+    /// [`ReqwestTransport`]: crate::transport::ReqwestTransport
+    pub fn transport<T>(mut self, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Uses an already configured [`reqwest::Client`] for all requests
+    ///
+    /// Note: When a client is provided, the [`connect_timeout`],
+    /// [`read_timeout`], [`timeout`], [`user_agent`], [`default_headers`] and
+    /// [`proxy`] options are ignored since the provided client is already
+    /// built.
+    ///
+    /// [`connect_timeout`]: Self::connect_timeout
+    /// [`read_timeout`]: Self::read_timeout
+    /// [`timeout`]: Self::timeout
+    /// [`user_agent`]: Self::user_agent
+    /// [`default_headers`]: Self::default_headers
+    /// [`proxy`]: Self::proxy
+    #[cfg(feature = "reqwest")]
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection to the endpoint
+    #[cfg(feature = "reqwest")]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for each read operation on an established connection
+    #[cfg(feature = "reqwest")]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request, from connecting until the response
+    /// body has been received
+    #[cfg(feature = "reqwest")]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request
+    ///
+    /// Defaults to `amtrak-api/{:version}`.
+    #[cfg(feature = "reqwest")]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Sets the headers sent with every request
+    #[cfg(feature = "reqwest")]
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Adds a proxy that requests will be routed through
+    #[cfg(feature = "reqwest")]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Sets the policy used to retry requests that failed due to a transient
+    /// error
+    ///
+    /// By default requests are not retried. The delay between retries is
+    /// waited using [`Transport::sleep`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the client side rate limit applied to every request
+    ///
+    /// The rate limit is shared by every clone of the built client. Note that
+    /// each retry made by the [`RetryPolicy`] counts as a separate request.
+    ///
+    /// By default requests are not rate limited. In [`RateLimitMode::Wait`]
+    /// the client waits using [`Transport::sleep`].
+    ///
+    /// [`RateLimitMode::Wait`]: crate::RateLimitMode::Wait
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Enables or disables deserialize diagnostics
+    ///
+    /// When enabled, a response that fails to deserialize returns an
+    /// [`Error::DeserializeFailed`] that includes the path of the offending
+    /// field (using [`serde_path_to_error`]) and the raw response body. This
+    /// comes at a small performance cost and the error will hold a copy of the
+    /// entire response.
+    ///
+    /// Defaults to `true` when the `serde_debugging` feature is enabled and
+    /// `false` otherwise.
     ///
-    /// [`station`]: Client::station
-    /// [`Error::Other`]: errors::Error::Other
     /// [`Error::DeserializeFailed`]: errors::Error::DeserializeFailed
-    #[cfg(feature = "serde_debugging")]
-    pub async fn station_with_debugging<S>(
-        &self,
-        station_code: S,
-    ) -> DebuggingResult<responses::StationResponse>
+    pub fn deserialize_diagnostics(mut self, enabled: bool) -> Self {
+        self.deserialize_diagnostics = Some(enabled);
+        self
+    }
+
+    /// Enables the lenient mode
+    ///
+    /// By default a single train or station that cannot be deserialized fails
+    /// the entire response of the `/trains` and `/stations` endpoints. In
+    /// lenient mode such entries are skipped and `on_skipped` is called with
+    /// the [`SkippedEntry`] describing each of them.
+    ///
+    /// See [`Client::trains_partial`] and [`Client::stations_partial`] to
+    /// receive the skipped entries along with the response instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use amtrak_api::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::builder()
+    ///         .lenient(|skipped| eprintln!("{skipped}"))
+    ///         .build()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn lenient<F>(mut self, on_skipped: F) -> Self
     where
-        S: AsRef<str>,
+        F: Fn(&SkippedEntry) + Send + Sync + 'static,
     {
-        let url = format!("{}/stations/{}", self.base_url, station_code.as_ref());
+        self.lenient = Some(SkipHandler::new(on_skipped));
+        self
+    }
+
+    /// Builds the [`Client`]
+    ///
+    /// This function returns [`Error::ClientBuildFailed`] if the underlying
+    /// HTTP client cannot be initialized.
+    ///
+    /// [`Error::ClientBuildFailed`]: errors::Error::ClientBuildFailed
+    pub fn build(self) -> Result<Client> {
+        let transport = match self.transport {
+            Some(transport) => transport,
+            #[cfg(feature = "reqwest")]
+            None => {
+                let http_client = match self.http_client {
+                    Some(http_client) => http_client,
+                    None => {
+                        let mut builder = reqwest::Client::builder()
+                            .user_agent(self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))
+                            .default_headers(self.default_headers);
+
+                        if let Some(timeout) = self.connect_timeout {
+                            builder = builder.connect_timeout(timeout);
+                        }
+
+                        if let Some(timeout) = self.read_timeout {
+                            builder = builder.read_timeout(timeout);
+                        }
+
+                        if let Some(timeout) = self.timeout {
+                            builder = builder.timeout(timeout);
+                        }
+
+                        for proxy in self.proxies {
+                            builder = builder.proxy(proxy);
+                        }
+
+                        builder.build().map_err(|err| {
+                            errors::Error::ClientBuildFailed(TransportError::from(err))
+                        })?
+                    }
+                };
+
+                Arc::new(ReqwestTransport::new(http_client))
+            }
+            #[cfg(not(feature = "reqwest"))]
+            None => {
+                return Err(errors::Error::ClientBuildFailed(TransportError::other(
+                    "No transport was provided and the `reqwest` feature is disabled",
+                )))
+            }
+        };
+
+        Ok(Client {
+            base_url: self.base_url.unwrap_or_else(|| BASE_API_URL.to_string()),
+            transport,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            deserialize_diagnostics: self
+                .deserialize_diagnostics
+                .unwrap_or(cfg!(feature = "serde_debugging")),
+            lenient: self.lenient,
+        })
+    }
 
-        let bytes = reqwest::Client::new()
-            .get(url)
-            .send()
-            .await?
-            .bytes()
-            .await?;
-
-        let response: responses::StationResponseWrapper = serde_path_to_error::deserialize(
-            &mut serde_json::Deserializer::from_slice(bytes.as_ref()),
-        )
-        .map_err(|err| errors::DebuggingError::DeserializeFailed {
-            error: err,
-            response: std::str::from_utf8(bytes.as_ref())
-                .unwrap_or("Failed to convert bytes to string")
-                .to_string(),
-        })?;
-
-        Ok(response.0)
+    /// Builds a [`blocking::Client`] using this configuration
+    ///
+    /// This function returns [`Error::ClientBuildFailed`] if the underlying
+    /// HTTP client or runtime cannot be initialized.
+    ///
+    /// [`blocking::Client`]: crate::blocking::Client
+    /// [`Error::ClientBuildFailed`]: errors::Error::ClientBuildFailed
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::Client> {
+        crate::blocking::Client::from_async(self.build()?)
     }
 }
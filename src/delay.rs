@@ -0,0 +1,131 @@
+//! Delays
+//!
+//! Computes how early or late a train is at each of its stations by comparing
+//! the scheduled times against the actual (or predicted) times.
+
+use std::cmp::Ordering;
+
+use chrono::Duration;
+
+use crate::responses::{Train, TrainStation, TrainStatus};
+
+/// Describes how the delay of a train is changing along its route
+///
+/// See [`Train::delay_trend`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DelayTrend {
+    /// The train is falling further behind schedule
+    Increasing,
+
+    /// The train is making up time
+    Decreasing,
+
+    /// The delay of the train has not changed
+    Steady,
+}
+
+impl TrainStation {
+    /// Returns the signed arrival delay at this station
+    ///
+    /// A positive value means the train arrived (or is predicted to arrive)
+    /// late while a negative value means it arrived early. Returns `None` when
+    /// the [`arrival`] time is not available.
+    ///
+    /// [`arrival`]: Self::arrival
+    pub fn arrival_delay(&self) -> Option<Duration> {
+        self.arrival
+            .map(|arrival| arrival.signed_duration_since(self.schedule_arrival))
+    }
+
+    /// Returns the signed departure delay at this station
+    ///
+    /// A positive value means the train departed (or is predicted to depart)
+    /// late while a negative value means it departed early. Returns `None`
+    /// when the [`departure`] time is not available.
+    ///
+    /// [`departure`]: Self::departure
+    pub fn departure_delay(&self) -> Option<Duration> {
+        self.departure
+            .map(|departure| departure.signed_duration_since(self.schedule_departure))
+    }
+
+    /// Returns the signed delay at this station
+    ///
+    /// This is the [`departure_delay`] falling back to the [`arrival_delay`]
+    /// when the departure time is not available.
+    ///
+    /// [`departure_delay`]: Self::departure_delay
+    /// [`arrival_delay`]: Self::arrival_delay
+    pub fn delay(&self) -> Option<Duration> {
+        self.departure_delay().or_else(|| self.arrival_delay())
+    }
+
+    /// Returns the amount of time the train is scheduled to spend at this
+    /// station
+    pub fn scheduled_dwell(&self) -> Duration {
+        self.schedule_departure
+            .signed_duration_since(self.schedule_arrival)
+    }
+
+    /// Returns the amount of time the train spent (or is predicted to spend)
+    /// at this station
+    ///
+    /// Returns `None` when either the [`arrival`] or [`departure`] time is not
+    /// available.
+    ///
+    /// [`arrival`]: Self::arrival
+    /// [`departure`]: Self::departure
+    pub fn actual_dwell(&self) -> Option<Duration> {
+        Some(self.departure?.signed_duration_since(self.arrival?))
+    }
+}
+
+impl Train {
+    /// Returns the largest delay of the train across all of its stations
+    ///
+    /// Stations the train has not yet reached contribute their predicted
+    /// delay. Returns `None` if no station has a known delay.
+    pub fn max_delay(&self) -> Option<Duration> {
+        self.stations
+            .iter()
+            .flat_map(|station| [station.arrival_delay(), station.departure_delay()])
+            .flatten()
+            .max()
+    }
+
+    /// Returns the delay of the train at the last station it departed from
+    ///
+    /// Returns `None` if the train has not departed from any station yet or
+    /// the delay at that station is not known.
+    pub fn last_departed_delay(&self) -> Option<Duration> {
+        self.stations
+            .iter()
+            .rev()
+            .find(|station| station.status == TrainStatus::Departed)?
+            .delay()
+    }
+
+    /// Returns how the delay of the train has changed across the stations it
+    /// has departed from
+    ///
+    /// The delay at the first departed station is compared against the delay
+    /// at the last departed station. Returns `None` if fewer than two
+    /// departed stations have a known delay.
+    pub fn delay_trend(&self) -> Option<DelayTrend> {
+        let mut delays = self
+            .stations
+            .iter()
+            .filter(|station| station.status == TrainStatus::Departed)
+            .filter_map(TrainStation::delay);
+
+        let first = delays.next()?;
+        let last = delays.next_back()?;
+
+        Some(match last.cmp(&first) {
+            Ordering::Greater => DelayTrend::Increasing,
+            Ordering::Less => DelayTrend::Decreasing,
+            Ordering::Equal => DelayTrend::Steady,
+        })
+    }
+}
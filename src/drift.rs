@@ -0,0 +1,189 @@
+//! Schema Drift
+//!
+//! Compares the fields present in a raw response against the fields of the
+//! [`Train`], [`TrainStation`] and [`Station`] data models. This allows
+//! callers to notice when the Amtrak API starts providing new fields (or stops
+//! providing existing ones) before it breaks deserialization.
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{
+    de::{self, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use serde_json::{Map, Value};
+
+use crate::responses::{Station, Train, TrainStation};
+
+/// The differences between a response and the data models of this library
+///
+/// Each field is identified by the name of the data model followed by the
+/// name of the field in the Amtrak API (for example `TrainStation.schArr`)
+/// and maps to the number of objects in the response where the drift was
+/// observed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let (_, report) = Client::new().trains_with_drift().await?;
+///
+///     for (field, count) in &report.unknown_fields {
+///         println!("Unknown field {field} was found {count} times");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Fields present in the response that are not part of the data model
+    pub unknown_fields: BTreeMap<String, usize>,
+
+    /// Fields of the data model that are not present in the response
+    pub missing_fields: BTreeMap<String, usize>,
+
+    /// Fields of the data model that are `null` in the response
+    pub null_fields: BTreeMap<String, usize>,
+}
+
+impl DriftReport {
+    /// Returns `true` if the response matched the data models exactly
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty()
+            && self.missing_fields.is_empty()
+            && self.null_fields.is_empty()
+    }
+
+    /// Creates a report from the raw response of the `/trains` or
+    /// `/trains/{:train_id}` endpoint
+    pub(crate) fn trains(value: &Value) -> Self {
+        let train_fields = field_names::<Train>();
+        let station_fields = field_names::<TrainStation>();
+        let mut report = Self::default();
+
+        for train in entries(value).flat_map(|trains| trains.as_array().into_iter().flatten()) {
+            let Some(train) = train.as_object() else {
+                continue;
+            };
+            report.record("Train", train_fields, train);
+
+            let stations = train.get("stations").and_then(Value::as_array);
+            for station in stations.into_iter().flatten().filter_map(Value::as_object) {
+                report.record("TrainStation", station_fields, station);
+            }
+        }
+
+        report
+    }
+
+    /// Creates a report from the raw response of the `/stations` or
+    /// `/stations/{:station_code}` endpoint
+    pub(crate) fn stations(value: &Value) -> Self {
+        let station_fields = field_names::<Station>();
+        let mut report = Self::default();
+
+        for station in entries(value).filter_map(Value::as_object) {
+            report.record("Station", station_fields, station);
+        }
+
+        report
+    }
+
+    /// Records the drift of a single object against the fields of its model
+    fn record(&mut self, model: &str, fields: &[&str], object: &Map<String, Value>) {
+        for key in object.keys() {
+            if !fields.contains(&key.as_str()) {
+                increment(&mut self.unknown_fields, model, key);
+            }
+        }
+
+        for field in fields {
+            match object.get(*field) {
+                None => increment(&mut self.missing_fields, model, field),
+                Some(Value::Null) => increment(&mut self.null_fields, model, field),
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+/// Increments the number of occurrences of the drift of `model.field`
+fn increment(drift: &mut BTreeMap<String, usize>, model: &str, field: &str) {
+    *drift.entry(format!("{model}.{field}")).or_default() += 1;
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no schema drift");
+        }
+
+        for (title, fields) in [
+            ("unknown", &self.unknown_fields),
+            ("missing", &self.missing_fields),
+            ("null", &self.null_fields),
+        ] {
+            for (field, count) in fields {
+                writeln!(f, "{title} field `{field}` ({count} occurrences)")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the values of a response map
+///
+/// On empty data the Amtrak API serializes an empty array instead of a map.
+fn entries(value: &Value) -> impl Iterator<Item = &Value> {
+    value.as_object().into_iter().flat_map(Map::values)
+}
+
+/// Returns the serialized names of the fields of a struct deriving
+/// [`Deserialize`]
+fn field_names<'de, T>() -> &'static [&'static str]
+where
+    T: Deserialize<'de>,
+{
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+/// A deserializer that captures the field names requested by a struct and
+/// then aborts
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::custom("expected a struct"))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        *self.0 = fields;
+        Err(de::Error::custom("field names captured"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
@@ -1,27 +1,134 @@
+use std::{fmt, time::Duration};
+
+use crate::transport::TransportError;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Unable to send the request: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+    RequestFailed(#[from] TransportError),
 
-    #[error("Unable to deserialize the received value: {0}")]
-    DeserializeFailed(#[from] serde_json::error::Error),
+    #[error(
+        "Unable to deserialize the received value: {error}{}",
+        path.as_ref().map(|path| format!(" (at `{path}`)")).unwrap_or_default()
+    )]
+    DeserializeFailed {
+        /// The underlying deserialization error
+        #[source]
+        error: serde_json::error::Error,
+
+        /// The path of the field that failed to deserialize. Only provided
+        /// when deserialize diagnostics are enabled.
+        path: Option<String>,
+
+        /// The raw response body. Only provided when deserialize diagnostics
+        /// are enabled.
+        response: Option<String>,
+    },
 
     #[error("API returned an error response: {0}")]
-    ApiErrorResponse(String),
+    ApiErrorResponse(#[from] ErrorResponse),
+
+    #[error("Unable to build the HTTP client: {0}")]
+    ClientBuildFailed(#[source] TransportError),
+
+    #[error("Client side rate limit reached, retry after {}ms", retry_after.as_millis())]
+    RateLimited { retry_after: Duration },
 }
 
-#[cfg(feature = "serde_debugging")]
-#[derive(Debug, thiserror::Error)]
-pub enum DebuggingError {
-    #[error("Unable to send the request: {0}")]
-    RequestFailed(#[from] reqwest::Error),
+/// An unsuccessful (non 2xx) HTTP response returned by the API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponse {
+    /// The HTTP status code of the response
+    ///
+    /// # Examples:
+    /// * `404`
+    /// * `429`
+    /// * `503`
+    pub status: u16,
 
-    #[error("Unable to deserialize the received value: {error}: {response}")]
-    DeserializeFailed {
-        error: serde_path_to_error::Error<serde_json::error::Error>,
-        response: String,
-    },
+    /// How long the API asked the caller to wait before retrying, as provided
+    /// by the `Retry-After` header. This is usually only provided alongside a
+    /// `429` or `503` status code.
+    pub retry_after: Option<Duration>,
 
-    #[error("API returned an error response: {0}")]
-    ApiErrorResponse(String),
+    /// The body of the response, truncated to the first 512 bytes.
+    pub body: String,
+}
+
+impl ErrorResponse {
+    /// Returns `true` if the API failed to handle the request (5xx)
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status)
+    }
+
+    /// Returns `true` if the API rejected the request (4xx)
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.status)
+    }
+}
+
+impl std::error::Error for ErrorResponse {}
+
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status {}", self.status)?;
+
+        if let Some(retry_after) = self.retry_after {
+            write!(f, " (retry after {}s)", retry_after.as_secs())?;
+        }
+
+        if !self.body.is_empty() {
+            write!(f, ": {}", self.body)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors that can occur while fetching a response from the API
+///
+/// This is converted into an [`Error`] by the endpoint functions and is used to
+/// decide whether a request can be retried.
+#[derive(Debug)]
+pub(crate) enum RequestError {
+    /// The request could not be sent or the body could not be received
+    Request(TransportError),
+
+    /// The API responded with an unsuccessful status code
+    Response(ErrorResponse),
+
+    /// The request was not sent because the client side rate limit was reached
+    RateLimited(Duration),
+}
+
+impl From<TransportError> for RequestError {
+    fn from(error: TransportError) -> Self {
+        Self::Request(error)
+    }
+}
+
+impl From<ErrorResponse> for RequestError {
+    fn from(response: ErrorResponse) -> Self {
+        Self::Response(response)
+    }
+}
+
+impl From<RequestError> for Error {
+    fn from(error: RequestError) -> Self {
+        match error {
+            RequestError::Request(error) => Self::RequestFailed(error),
+            RequestError::Response(response) => Self::ApiErrorResponse(response),
+            RequestError::RateLimited(retry_after) => Self::RateLimited { retry_after },
+        }
+    }
+}
+
+impl From<serde_json::error::Error> for Error {
+    fn from(error: serde_json::error::Error) -> Self {
+        Self::DeserializeFailed {
+            error,
+            path: None,
+            response: None,
+        }
+    }
 }
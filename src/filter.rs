@@ -0,0 +1,370 @@
+//! Train Filtering
+//!
+//! Selects the trains of a [`TrainResponse`] whose current location falls
+//! inside a [`BoundingBox`] or a [`Polygon`] (for example a metro area or a
+//! state boundary supplied as GeoJSON), optionally combined with the route and
+//! the [`TrainState`] of the train.
+//!
+//! Locations are treated as planar longitude and latitude coordinates which is
+//! accurate enough for the areas the trains of this API operate in. Areas
+//! crossing the antimeridian are not supported.
+
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::{
+    geometry::{Coordinates, Located},
+    responses::{Train, TrainResponse, TrainState},
+};
+
+/// An area delimited by a minimum and maximum latitude and longitude
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoundingBox {
+    /// The south west corner of the area
+    pub south_west: Coordinates,
+
+    /// The north east corner of the area
+    pub north_east: Coordinates,
+}
+
+impl BoundingBox {
+    /// Creates a new bounding box from two opposite corners
+    pub fn new(corner: Coordinates, opposite: Coordinates) -> Self {
+        Self {
+            south_west: Coordinates::new(
+                corner.lat.min(opposite.lat),
+                corner.lon.min(opposite.lon),
+            ),
+            north_east: Coordinates::new(
+                corner.lat.max(opposite.lat),
+                corner.lon.max(opposite.lon),
+            ),
+        }
+    }
+
+    /// Returns `true` if the location is inside the area (borders included)
+    pub fn contains<L>(&self, location: &L) -> bool
+    where
+        L: Located + ?Sized,
+    {
+        let Coordinates { lat, lon } = location.coordinates();
+
+        (self.south_west.lat..=self.north_east.lat).contains(&lat)
+            && (self.south_west.lon..=self.north_east.lon).contains(&lon)
+    }
+}
+
+/// The error returned when a GeoJSON document cannot be parsed into a
+/// [`Polygon`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid GeoJSON polygon: {reason}")]
+pub struct ParseGeoJsonError {
+    reason: String,
+}
+
+impl ParseGeoJsonError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+/// An arbitrary area made of one or more polygons which may contain holes
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::{Coordinates, Polygon};
+///
+/// let area: Polygon = r#"{
+///     "type": "Polygon",
+///     "coordinates": [[[-75.3, 39.8], [-73.8, 39.8], [-73.8, 40.9], [-75.3, 40.9], [-75.3, 39.8]]]
+/// }"#
+/// .parse()?;
+///
+/// assert!(area.contains(&Coordinates::new(40.2177, -74.7550)));
+/// assert!(!area.contains(&Coordinates::new(38.8973, -77.0063)));
+/// # Ok::<(), amtrak_api::ParseGeoJsonError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    /// Every polygon of the area, each being a list of rings where the first
+    /// ring is the exterior and the remaining rings are holes
+    polygons: Vec<Vec<Vec<Coordinates>>>,
+}
+
+impl Polygon {
+    /// Creates a new polygon from the vertices of its exterior ring
+    ///
+    /// The ring does not need to be closed (the last vertex is implicitly
+    /// connected to the first one).
+    pub fn new(exterior: Vec<Coordinates>) -> Self {
+        Self {
+            polygons: vec![vec![exterior]],
+        }
+    }
+
+    /// Parses a polygon from a GeoJSON document
+    ///
+    /// The document can either be a `Polygon` or a `MultiPolygon` geometry, a
+    /// `Feature` containing one of these geometries or a `FeatureCollection`,
+    /// in which case the area is made of every polygon of the collection.
+    /// Features with any other geometry are ignored.
+    pub fn from_geojson(geojson: &str) -> Result<Self, ParseGeoJsonError> {
+        let value: Value =
+            serde_json::from_str(geojson).map_err(|err| ParseGeoJsonError::new(err.to_string()))?;
+
+        let mut polygons = Vec::new();
+        collect_polygons(&value, &mut polygons)?;
+
+        if polygons.is_empty() {
+            return Err(ParseGeoJsonError::new("no polygon found"));
+        }
+
+        Ok(Self { polygons })
+    }
+
+    /// Returns `true` if the location is inside the area
+    ///
+    /// A location is inside a polygon when it is inside its exterior ring and
+    /// outside all of its holes.
+    pub fn contains<L>(&self, location: &L) -> bool
+    where
+        L: Located + ?Sized,
+    {
+        let point = location.coordinates();
+
+        self.polygons.iter().any(|rings| {
+            rings
+                .iter()
+                .filter(|ring| ring_contains(ring, point))
+                .count()
+                % 2
+                == 1
+        })
+    }
+}
+
+impl FromStr for Polygon {
+    type Err = ParseGeoJsonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_geojson(s)
+    }
+}
+
+/// Returns `true` if the point is inside the ring using the even-odd rule
+fn ring_contains(ring: &[Coordinates], point: Coordinates) -> bool {
+    let mut inside = false;
+    let Some(mut previous) = ring.last() else {
+        return false;
+    };
+
+    for vertex in ring {
+        if (vertex.lat > point.lat) != (previous.lat > point.lat) {
+            let lon = vertex.lon
+                + (point.lat - vertex.lat) * (previous.lon - vertex.lon)
+                    / (previous.lat - vertex.lat);
+            if point.lon < lon {
+                inside = !inside;
+            }
+        }
+        previous = vertex;
+    }
+
+    inside
+}
+
+/// Collects every polygon of a GeoJSON object
+fn collect_polygons(
+    value: &Value,
+    polygons: &mut Vec<Vec<Vec<Coordinates>>>,
+) -> Result<(), ParseGeoJsonError> {
+    let kind = value
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ParseGeoJsonError::new("missing `type` member"))?;
+
+    match kind {
+        "Polygon" => polygons.push(parse_polygon(coordinates(value)?)?),
+        "MultiPolygon" => {
+            for polygon in array(coordinates(value)?, "coordinates")? {
+                polygons.push(parse_polygon(polygon)?);
+            }
+        }
+        "Feature" => match value.get("geometry") {
+            Some(Value::Null) | None => {}
+            Some(geometry) => collect_polygons(geometry, polygons)?,
+        },
+        "FeatureCollection" => {
+            let features = value
+                .get("features")
+                .ok_or_else(|| ParseGeoJsonError::new("missing `features` member"))?;
+            for feature in array(features, "features")? {
+                collect_polygons(feature, polygons)?;
+            }
+        }
+        "GeometryCollection" => {
+            let geometries = value
+                .get("geometries")
+                .ok_or_else(|| ParseGeoJsonError::new("missing `geometries` member"))?;
+            for geometry in array(geometries, "geometries")? {
+                collect_polygons(geometry, polygons)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Returns the `coordinates` member of a GeoJSON geometry
+fn coordinates(geometry: &Value) -> Result<&Value, ParseGeoJsonError> {
+    geometry
+        .get("coordinates")
+        .ok_or_else(|| ParseGeoJsonError::new("missing `coordinates` member"))
+}
+
+/// Returns the elements of a JSON array
+fn array<'a>(value: &'a Value, name: &str) -> Result<&'a Vec<Value>, ParseGeoJsonError> {
+    value
+        .as_array()
+        .ok_or_else(|| ParseGeoJsonError::new(format!("`{name}` is not an array")))
+}
+
+/// Parses the rings of a GeoJSON polygon
+fn parse_polygon(polygon: &Value) -> Result<Vec<Vec<Coordinates>>, ParseGeoJsonError> {
+    array(polygon, "coordinates")?
+        .iter()
+        .map(|ring| {
+            array(ring, "coordinates")?
+                .iter()
+                .map(|position| {
+                    // GeoJSON positions are ordered longitude then latitude
+                    match array(position, "coordinates")?.as_slice() {
+                        [lon, lat, ..] => match (lon.as_f64(), lat.as_f64()) {
+                            (Some(lon), Some(lat)) => Ok(Coordinates::new(lat, lon)),
+                            _ => Err(ParseGeoJsonError::new("position is not a number")),
+                        },
+                        _ => Err(ParseGeoJsonError::new(
+                            "position has less than two elements",
+                        )),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A query selecting trains by location, route and state
+///
+/// Every criteria that is set must match for a train to be selected. When
+/// multiple routes or states are provided, a train only needs to match one of
+/// them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::{BoundingBox, Client, Coordinates, TrainFilter, TrainState};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let northeast_corridor = BoundingBox::new(
+///         Coordinates::new(38.7, -77.2),
+///         Coordinates::new(42.5, -70.9),
+///     );
+///
+///     let trains = TrainFilter::new()
+///         .bounding_box(northeast_corridor)
+///         .state(TrainState::Active)
+///         .apply(Client::new().trains().await?);
+///
+///     for train in trains.values().flatten() {
+///         println!("{} {}", train.route_name, train.train_id);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrainFilter {
+    bounding_box: Option<BoundingBox>,
+    polygon: Option<Polygon>,
+    routes: Vec<String>,
+    states: Vec<TrainState>,
+}
+
+impl TrainFilter {
+    /// Creates a new filter that selects every train
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only select trains located inside the bounding box
+    pub fn bounding_box(mut self, bounding_box: BoundingBox) -> Self {
+        self.bounding_box = Some(bounding_box);
+        self
+    }
+
+    /// Only select trains located inside the polygon
+    pub fn polygon(mut self, polygon: Polygon) -> Self {
+        self.polygon = Some(polygon);
+        self
+    }
+
+    /// Only select trains running on the provided route (see
+    /// [`route_name`])
+    ///
+    /// [`route_name`]: Train::route_name
+    pub fn route<S>(mut self, route_name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.routes.push(route_name.into());
+        self
+    }
+
+    /// Only select trains in the provided state (see [`train_state`])
+    ///
+    /// [`train_state`]: Train::train_state
+    pub fn state(mut self, state: TrainState) -> Self {
+        self.states.push(state);
+        self
+    }
+
+    /// Returns `true` if the train matches every criteria of the filter
+    // `Option::is_none_or` requires Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn matches(&self, train: &Train) -> bool {
+        self.bounding_box
+            .map_or(true, |bounding_box| bounding_box.contains(train))
+            && self
+                .polygon
+                .as_ref()
+                .map_or(true, |polygon| polygon.contains(train))
+            && (self.routes.is_empty() || self.routes.contains(&train.route_name))
+            && (self.states.is_empty() || self.states.contains(&train.train_state))
+    }
+
+    /// Returns the trains of the response matching the filter
+    pub fn matching<'a>(&self, trains: &'a TrainResponse) -> Vec<&'a Train> {
+        trains
+            .values()
+            .flatten()
+            .filter(|train| self.matches(train))
+            .collect()
+    }
+
+    /// Removes the trains of the response not matching the filter
+    ///
+    /// Train numbers left without any train are removed from the response.
+    pub fn apply(&self, mut trains: TrainResponse) -> TrainResponse {
+        trains.retain(|_, trains| {
+            trains.retain(|train| self.matches(train));
+            !trains.is_empty()
+        });
+        trains
+    }
+}
@@ -0,0 +1,100 @@
+//! Geo Types
+//!
+//! Conversions from the data models of this library into [`geo_types`] so
+//! they can be used with the [`geo`](https://crates.io/crates/geo) ecosystem
+//! (simplification, intersections, projections, etc).
+//!
+//! Following the [`geo_types`] convention, the `x` coordinate is the
+//! longitude and the `y` coordinate is the latitude.
+
+use geo_types::{Coord, LineString, Point};
+
+use crate::{
+    geometry::{Coordinates, Located},
+    responses::{Station, StationResponse, Train, TrainStation},
+};
+
+impl From<Coordinates> for Coord<f64> {
+    fn from(coordinates: Coordinates) -> Self {
+        Coord {
+            x: coordinates.lon,
+            y: coordinates.lat,
+        }
+    }
+}
+
+impl From<Coordinates> for Point<f64> {
+    fn from(coordinates: Coordinates) -> Self {
+        Point(coordinates.into())
+    }
+}
+
+impl From<Coord<f64>> for Coordinates {
+    fn from(coord: Coord<f64>) -> Self {
+        Self::new(coord.y, coord.x)
+    }
+}
+
+impl From<Point<f64>> for Coordinates {
+    fn from(point: Point<f64>) -> Self {
+        point.0.into()
+    }
+}
+
+impl From<&Station> for Point<f64> {
+    fn from(station: &Station) -> Self {
+        station.coordinates().into()
+    }
+}
+
+impl From<&Train> for Point<f64> {
+    fn from(train: &Train) -> Self {
+        train.coordinates().into()
+    }
+}
+
+impl TrainStation {
+    /// Returns the location of this station as a [`Point`]
+    ///
+    /// The location is looked up in the provided stations, see
+    /// [`coordinates`]. Returns `None` if the station is not part of
+    /// `stations`.
+    ///
+    /// [`coordinates`]: Self::coordinates
+    pub fn point(&self, stations: &StationResponse) -> Option<Point<f64>> {
+        self.coordinates(stations).map(Point::from)
+    }
+}
+
+impl Train {
+    /// Returns the stop sequence of the train as a [`LineString`]
+    ///
+    /// Stations are looked up in the provided stations and the stations that
+    /// are not part of `stations` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use amtrak_api::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let stations = client.stations().await?;
+    ///
+    ///     for train in client.train("2150").await?.values().flatten() {
+    ///         let route = train.line_string(&stations);
+    ///         println!("{} has {} known stops", train.train_id, route.0.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn line_string(&self, stations: &StationResponse) -> LineString<f64> {
+        self.stations
+            .iter()
+            .filter_map(|station| station.coordinates(stations))
+            .map(Coord::from)
+            .collect()
+    }
+}
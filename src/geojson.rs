@@ -0,0 +1,219 @@
+//! GeoJSON Serialization
+//!
+//! The [`GeoJson`] wrapper serializes a [`TrainResponse`] or a
+//! [`StationResponse`] as a GeoJSON `FeatureCollection` which can be handed
+//! directly to mapping libraries such as Mapbox or Leaflet.
+//!
+//! Features are ordered by train id or station code so the output is stable
+//! between calls and properties use the Rust field names (see [`Clean`]).
+//!
+//! [`Clean`]: crate::Clean
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    geometry::{Coordinates, Located},
+    responses::{Heading, Provider, Station, StationResponse, Train, TrainResponse, TrainState},
+    timeliness::TimelinessComment,
+    train_id::TrainId,
+};
+
+/// Serializes the wrapped response as a GeoJSON `FeatureCollection`
+///
+/// Every train is serialized as a `Point` feature with the `route_name`,
+/// `train_id`, `train_num`, `train_timely`, `train_timely_minutes`,
+/// `velocity`, `heading`, `train_state` and `provider` properties. The
+/// `train_timely_minutes` property is the delay of the train in minutes
+/// (negative when the train is early) or `null` when the timeliness is
+/// unknown. Every station is serialized as a `Point` feature with the
+/// `code`, `name`, `tz`, `city` and `state` properties.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::{Client, GeoJson};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///     let trains = client.trains().await?;
+///     let stations = client.stations().await?;
+///
+///     let geojson = serde_json::to_string(&GeoJson::new(&trains).with_routes(&stations))?;
+///     std::fs::write("trains.geojson", geojson)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct GeoJson<'a, T> {
+    value: &'a T,
+    routes: Option<&'a StationResponse>,
+}
+
+impl<'a, T> GeoJson<'a, T> {
+    /// Wraps the provided response
+    pub fn new(value: &'a T) -> Self {
+        Self {
+            value,
+            routes: None,
+        }
+    }
+}
+
+impl<'a> GeoJson<'a, TrainResponse> {
+    /// Adds a `LineString` feature for the route of every train
+    ///
+    /// The route is built from the [`stations`] of the train whose locations
+    /// are looked up in the provided stations. Stations that are not part of
+    /// `stations` are skipped and a route is only added when at least two of
+    /// its stations are located. Route features have the `route_name` and
+    /// `train_id` properties.
+    ///
+    /// [`stations`]: Train::stations
+    pub fn with_routes(mut self, stations: &'a StationResponse) -> Self {
+        self.routes = Some(stations);
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename = "FeatureCollection")]
+struct FeatureCollection<'a> {
+    features: Vec<Feature<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename = "Feature")]
+struct Feature<'a> {
+    geometry: Geometry,
+    properties: Properties<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", content = "coordinates")]
+enum Geometry {
+    Point([f64; 2]),
+    LineString(Vec<[f64; 2]>),
+}
+
+/// Returns a GeoJSON position (longitude followed by latitude)
+fn position(coordinates: Coordinates) -> [f64; 2] {
+    [coordinates.lon, coordinates.lat]
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Properties<'a> {
+    Train {
+        route_name: &'a str,
+        train_id: &'a TrainId,
+        train_num: &'a str,
+        train_timely: &'a TimelinessComment,
+        train_timely_minutes: Option<i64>,
+        velocity: f32,
+        heading: &'a Heading,
+        train_state: &'a TrainState,
+        provider: &'a Provider,
+    },
+    Route {
+        route_name: &'a str,
+        train_id: &'a TrainId,
+    },
+    Station {
+        code: &'a str,
+        name: &'a str,
+        tz: &'a str,
+        city: &'a str,
+        state: &'a str,
+    },
+}
+
+impl<'a> Feature<'a> {
+    fn train(train: &'a Train) -> Self {
+        Self {
+            geometry: Geometry::Point(position(train.coordinates())),
+            properties: Properties::Train {
+                route_name: &train.route_name,
+                train_id: &train.train_id,
+                train_num: &train.train_num,
+                train_timely: &train.train_timely,
+                train_timely_minutes: train.train_timely.delay().map(|delay| delay.num_minutes()),
+                velocity: train.velocity,
+                heading: &train.heading,
+                train_state: &train.train_state,
+                provider: &train.provider,
+            },
+        }
+    }
+
+    fn route(train: &'a Train, stations: &StationResponse) -> Option<Self> {
+        let positions: Vec<_> = train
+            .stations
+            .iter()
+            .filter_map(|station| station.coordinates(stations))
+            .map(position)
+            .collect();
+
+        if positions.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            geometry: Geometry::LineString(positions),
+            properties: Properties::Route {
+                route_name: &train.route_name,
+                train_id: &train.train_id,
+            },
+        })
+    }
+
+    fn station(station: &'a Station) -> Self {
+        Self {
+            geometry: Geometry::Point(position(station.coordinates())),
+            properties: Properties::Station {
+                code: &station.code,
+                name: &station.name,
+                tz: &station.tz,
+                city: &station.city,
+                state: &station.state,
+            },
+        }
+    }
+}
+
+impl Serialize for GeoJson<'_, TrainResponse> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut trains: Vec<_> = self.value.values().flatten().collect();
+        trains.sort_by(|lhs, rhs| lhs.train_id.cmp(&rhs.train_id));
+
+        let mut features: Vec<_> = trains.iter().map(|train| Feature::train(train)).collect();
+        if let Some(stations) = self.routes {
+            features.extend(
+                trains
+                    .iter()
+                    .filter_map(|train| Feature::route(train, stations)),
+            );
+        }
+
+        FeatureCollection { features }.serialize(serializer)
+    }
+}
+
+impl Serialize for GeoJson<'_, StationResponse> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut stations: Vec<_> = self.value.values().collect();
+        stations.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+
+        FeatureCollection {
+            features: stations.into_iter().map(Feature::station).collect(),
+        }
+        .serialize(serializer)
+    }
+}
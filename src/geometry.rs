@@ -0,0 +1,357 @@
+//! Geometry
+//!
+//! Helpers to convert a [`Heading`] into a bearing and to compute the bearing
+//! and great circle distance between two locations, for example to rotate a
+//! train icon on a map, to detect trains whose reported heading disagrees with
+//! their route or to find the closest station to a user.
+
+use std::fmt;
+
+use crate::responses::{Heading, Station, StationResponse, Train, TrainResponse, TrainStation};
+
+/// The mean radius of the earth in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A location on the earth using decimal degrees
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Coordinates {
+    /// The latitude in decimal degrees
+    pub lat: f64,
+
+    /// The longitude in decimal degrees
+    pub lon: f64,
+}
+
+impl Coordinates {
+    /// Creates new coordinates from a latitude and a longitude
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+
+    /// Returns the initial bearing (in degrees clockwise from north between
+    /// `0.0` and `360.0`) of the great circle path to `other`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use amtrak_api::Coordinates;
+    ///
+    /// let philadelphia = Coordinates::new(39.9557, -75.1822);
+    /// let new_york = Coordinates::new(40.7506, -73.9935);
+    ///
+    /// let bearing = philadelphia.bearing_to(&new_york);
+    /// assert!((bearing - 48.3).abs() < 0.1);
+    /// ```
+    pub fn bearing_to(&self, other: &Coordinates) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let delta_lon = (other.lon - self.lon).to_radians();
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+/// An entity with a location on the earth
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::{Coordinates, Located};
+///
+/// let philadelphia = Coordinates::new(39.9557, -75.1822);
+/// let new_york = Coordinates::new(40.7506, -73.9935);
+///
+/// let distance = philadelphia.distance_to(&new_york);
+/// assert!((distance - 134.0).abs() < 0.1);
+/// ```
+pub trait Located {
+    /// Returns the location of the entity
+    fn coordinates(&self) -> Coordinates;
+
+    /// Returns the great circle distance (in kilometers) to `other` using the
+    /// haversine formula
+    fn distance_to<L>(&self, other: &L) -> f64
+    where
+        L: Located + ?Sized,
+    {
+        let (from, to) = (self.coordinates(), other.coordinates());
+        let (lat1, lat2) = (from.lat.to_radians(), to.lat.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (to.lon - from.lon).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+impl Located for Coordinates {
+    fn coordinates(&self) -> Coordinates {
+        *self
+    }
+}
+
+impl Located for Station {
+    fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.lat, self.lon)
+    }
+}
+
+impl Located for Train {
+    fn coordinates(&self) -> Coordinates {
+        Coordinates::new(self.lat, self.lon)
+    }
+}
+
+/// Distance queries over a collection of [`Located`] entities
+///
+/// This trait is implemented for [`StationResponse`], [`TrainResponse`] and
+/// slices of located entities. Every query returns the matching entities along
+/// with their distance (in kilometers) sorted from closest to farthest.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::{Client, Coordinates, Proximity};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let stations = Client::new().stations().await?;
+///     let city_hall = Coordinates::new(39.9526, -75.1635);
+///
+///     for (station, distance) in stations.within_radius(&city_hall, 10.0) {
+///         println!("{} is {distance:.1} km away", station.name);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Proximity {
+    /// The located entity of the collection
+    type Item: Located;
+
+    /// Returns an iterator over every entity of the collection
+    fn located(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_>;
+
+    /// Returns the `count` closest entities to `point`
+    fn nearest<L>(&self, point: &L, count: usize) -> Vec<(&Self::Item, f64)>
+    where
+        L: Located + ?Sized,
+    {
+        let mut entities = distances(self.located(), point);
+        entities.truncate(count);
+        entities
+    }
+
+    /// Returns every entity within `radius` kilometers of `point`
+    fn within_radius<L>(&self, point: &L, radius: f64) -> Vec<(&Self::Item, f64)>
+    where
+        L: Located + ?Sized,
+    {
+        let mut entities = distances(self.located(), point);
+        entities.retain(|(_, distance)| *distance <= radius);
+        entities
+    }
+}
+
+/// Returns the distance of every entity to `point` sorted from closest to
+/// farthest
+fn distances<'a, T, L>(entities: impl Iterator<Item = &'a T>, point: &L) -> Vec<(&'a T, f64)>
+where
+    T: Located + 'a,
+    L: Located + ?Sized,
+{
+    let mut entities: Vec<_> = entities
+        .map(|entity| (entity, entity.distance_to(point)))
+        .collect();
+    entities.sort_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs));
+    entities
+}
+
+impl Proximity for StationResponse {
+    type Item = Station;
+
+    fn located(&self) -> Box<dyn Iterator<Item = &Station> + '_> {
+        Box::new(self.values())
+    }
+}
+
+impl Proximity for TrainResponse {
+    type Item = Train;
+
+    fn located(&self) -> Box<dyn Iterator<Item = &Train> + '_> {
+        Box::new(self.values().flatten())
+    }
+}
+
+impl<T> Proximity for [T]
+where
+    T: Located,
+{
+    type Item = T;
+
+    fn located(&self) -> Box<dyn Iterator<Item = &T> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl Heading {
+    /// Returns the bearing of this heading in degrees clockwise from north
+    ///
+    /// Returns `None` for a heading that is not known by this library.
+    pub fn degrees(&self) -> Option<f64> {
+        let degrees = match self {
+            Self::N => 0.0,
+            Self::NE => 45.0,
+            Self::E => 90.0,
+            Self::SE => 135.0,
+            Self::S => 180.0,
+            Self::SW => 225.0,
+            Self::W => 270.0,
+            Self::NW => 315.0,
+            _ => return None,
+        };
+
+        Some(degrees)
+    }
+
+    /// Returns the unit vector of this heading as `(east, north)` components
+    ///
+    /// Returns `None` for a heading that is not known by this library.
+    pub fn unit_vector(&self) -> Option<(f64, f64)> {
+        let radians = self.degrees()?.to_radians();
+        Some((radians.sin(), radians.cos()))
+    }
+
+    /// Returns the closest compass point of the provided bearing (in degrees
+    /// clockwise from north)
+    ///
+    /// Returns `None` if the bearing is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use amtrak_api::Heading;
+    ///
+    /// assert_eq!(Heading::from_degrees(10.0), Some(Heading::N));
+    /// assert_eq!(Heading::from_degrees(350.0), Some(Heading::N));
+    /// assert_eq!(Heading::from_degrees(-90.0), Some(Heading::W));
+    /// assert_eq!(Heading::from_degrees(f64::NAN), None);
+    /// ```
+    pub fn from_degrees(degrees: f64) -> Option<Self> {
+        if !degrees.is_finite() {
+            return None;
+        }
+
+        let heading = match ((degrees.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8 {
+            0 => Self::N,
+            1 => Self::NE,
+            2 => Self::E,
+            3 => Self::SE,
+            4 => Self::S,
+            5 => Self::SW,
+            6 => Self::W,
+            _ => Self::NW,
+        };
+
+        Some(heading)
+    }
+
+    /// Returns the smallest angle (between `0.0` and `180.0` degrees) between
+    /// this heading and the provided bearing
+    ///
+    /// Returns `None` for a heading that is not known by this library.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use amtrak_api::Heading;
+    ///
+    /// assert_eq!(Heading::NW.angular_difference(10.0), Some(55.0));
+    /// assert_eq!(Heading::N.angular_difference(Heading::S.degrees().unwrap()), Some(180.0));
+    /// ```
+    pub fn angular_difference(&self, bearing: f64) -> Option<f64> {
+        let difference = (self.degrees()? - bearing).rem_euclid(360.0);
+        Some(difference.min(360.0 - difference))
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let heading = match self {
+            Self::N => "N",
+            Self::NE => "NE",
+            Self::E => "E",
+            Self::SE => "SE",
+            Self::S => "S",
+            Self::SW => "SW",
+            Self::W => "W",
+            Self::NW => "NW",
+            Self::Other(heading) => heading,
+        };
+
+        f.write_str(heading)
+    }
+}
+
+impl Train {
+    /// Returns the closest station to the current location of the train along
+    /// with its distance (in kilometers)
+    ///
+    /// Returns `None` if `stations` is empty.
+    pub fn nearest_station<'a>(&self, stations: &'a StationResponse) -> Option<(&'a Station, f64)> {
+        stations.nearest(self, 1).into_iter().next()
+    }
+
+    /// Returns the bearing from the current location of the train to its next
+    /// station (see [`next_station`])
+    ///
+    /// Returns `None` if the train has no next station or the next station is
+    /// not part of `stations`.
+    ///
+    /// [`next_station`]: Self::next_station
+    pub fn bearing_to_next_station(&self, stations: &StationResponse) -> Option<f64> {
+        let next_station = self.next_station()?.coordinates(stations)?;
+        Some(self.coordinates().bearing_to(&next_station))
+    }
+
+    /// Returns the angle (between `0.0` and `180.0` degrees) between the
+    /// reported [`heading`] of the train and the bearing to its next station
+    ///
+    /// A large deviation means the reported heading disagrees with the route
+    /// of the train. Returns `None` if the heading is not known or the bearing
+    /// cannot be computed (see [`bearing_to_next_station`]).
+    ///
+    /// [`heading`]: Self::heading
+    /// [`bearing_to_next_station`]: Self::bearing_to_next_station
+    pub fn heading_deviation(&self, stations: &StationResponse) -> Option<f64> {
+        self.heading
+            .angular_difference(self.bearing_to_next_station(stations)?)
+    }
+}
+
+impl TrainStation {
+    /// Returns the location of this station
+    ///
+    /// A train station does not include its location so it is looked up by
+    /// [`code`] in the provided stations. Returns `None` if the station is not
+    /// part of `stations`.
+    ///
+    /// [`code`]: Self::code
+    pub fn coordinates(&self, stations: &StationResponse) -> Option<Coordinates> {
+        stations.get(&self.code).map(Station::coordinates)
+    }
+
+    /// Returns the bearing from this station to the `other` station
+    ///
+    /// Returns `None` if either station is not part of `stations`.
+    pub fn bearing_to(&self, other: &TrainStation, stations: &StationResponse) -> Option<f64> {
+        Some(
+            self.coordinates(stations)?
+                .bearing_to(&other.coordinates(stations)?),
+        )
+    }
+}
@@ -0,0 +1,259 @@
+//! GTFS Static
+//!
+//! Bridges the stations endpoint with [GTFS](https://gtfs.org/schedule/)
+//! static schedules:
+//!
+//! * [`write_stops`] exports a [`StationResponse`] as a `stops.txt` file.
+//! * [`write_agency`] exports an `agency.txt` file.
+//! * [`read_stops`] imports the stops of an existing `stops.txt` file (for
+//!   example from Amtrak's official GTFS feed) which can then be matched with
+//!   the stations of the API using [`cross_reference`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//!
+//! use amtrak_api::{gtfs, Client};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let stations = Client::new().stations().await?;
+//!
+//!     gtfs::write_stops(&stations, File::create("stops.txt")?)?;
+//!     gtfs::write_agency(&[gtfs::Agency::default()], File::create("agency.txt")?)?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::responses::{Station, StationResponse};
+
+/// Errors that can occur while reading or writing a GTFS file
+#[derive(Debug, thiserror::Error)]
+pub enum GtfsError {
+    #[error("Unable to read or write the CSV record: {0}")]
+    Csv(#[from] csv::Error),
+
+    #[error("Unable to write the GTFS file: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// A record of a GTFS `agency.txt` file
+///
+/// The default agency describes Amtrak.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Agency {
+    /// The identifier of the agency, required when a feed contains multiple
+    /// agencies
+    pub agency_id: Option<String>,
+
+    /// The full name of the agency
+    pub agency_name: String,
+
+    /// The URL of the agency
+    pub agency_url: String,
+
+    /// The IANA time zone of the agency
+    pub agency_timezone: String,
+
+    /// The primary language used by the agency
+    pub agency_lang: Option<String>,
+
+    /// The voice telephone number of the agency
+    pub agency_phone: Option<String>,
+}
+
+impl Default for Agency {
+    fn default() -> Self {
+        Self {
+            agency_id: None,
+            agency_name: "Amtrak".to_string(),
+            agency_url: "https://www.amtrak.com".to_string(),
+            agency_timezone: "America/New_York".to_string(),
+            agency_lang: Some("en".to_string()),
+            agency_phone: Some("1-800-872-7245".to_string()),
+        }
+    }
+}
+
+/// A record of a GTFS `stops.txt` file
+///
+/// Only the fields relevant to cross referencing stations are read, the other
+/// columns of the file are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stop {
+    /// The identifier of the stop
+    pub stop_id: String,
+
+    /// The short code of the stop displayed to riders
+    #[serde(default)]
+    pub stop_code: Option<String>,
+
+    /// The name of the stop
+    #[serde(default)]
+    pub stop_name: Option<String>,
+
+    /// The latitude of the stop in decimal degrees
+    #[serde(default)]
+    pub stop_lat: Option<f64>,
+
+    /// The longitude of the stop in decimal degrees
+    #[serde(default)]
+    pub stop_lon: Option<f64>,
+
+    /// The IANA time zone of the stop
+    #[serde(default)]
+    pub stop_timezone: Option<String>,
+}
+
+impl From<&Station> for Stop {
+    fn from(station: &Station) -> Self {
+        Self {
+            stop_id: station.code.clone(),
+            stop_code: None,
+            stop_name: Some(station.name.clone()),
+            stop_lat: Some(station.lat),
+            stop_lon: Some(station.lon),
+            stop_timezone: Some(station.tz.clone()),
+        }
+    }
+}
+
+/// Writes the stations as a GTFS `stops.txt` file
+///
+/// Every station is written as the [`Stop`] converted from it: the `stop_id`
+/// is the [`code`] of the station, the `stop_timezone` is its [`tz`] and the
+/// `stop_code` is left empty. Stops are written in the order of their
+/// `stop_id`.
+///
+/// [`code`]: Station::code
+/// [`tz`]: Station::tz
+pub fn write_stops<W>(stations: &StationResponse, writer: W) -> Result<(), GtfsError>
+where
+    W: io::Write,
+{
+    let mut stations: Vec<_> = stations.values().collect();
+    stations.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+
+    let mut writer = csv::Writer::from_writer(writer);
+    for station in stations {
+        writer.serialize(Stop::from(station))?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes the agencies as a GTFS `agency.txt` file
+pub fn write_agency<W>(agencies: &[Agency], writer: W) -> Result<(), GtfsError>
+where
+    W: io::Write,
+{
+    let mut writer = csv::Writer::from_writer(writer);
+    for agency in agencies {
+        writer.serialize(agency)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Reads the stops of a GTFS `stops.txt` file
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::gtfs;
+///
+/// let stops = gtfs::read_stops(
+///     "stop_id,stop_name,stop_lat,stop_lon,stop_timezone,location_type\n\
+///      PHL,Philadelphia 30th Street,39.9557,-75.1822,America/New_York,0\n"
+///         .as_bytes(),
+/// )?;
+///
+/// assert_eq!(stops[0].stop_id, "PHL");
+/// assert_eq!(stops[0].stop_code, None);
+/// # Ok::<(), gtfs::GtfsError>(())
+/// ```
+pub fn read_stops<R>(reader: R) -> Result<Vec<Stop>, GtfsError>
+where
+    R: io::Read,
+{
+    let stops = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(reader)
+        .deserialize()
+        .collect::<Result<_, _>>()?;
+
+    Ok(stops)
+}
+
+/// The result of matching the stops of a GTFS feed with the stations of the
+/// API (see [`cross_reference`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossReference<'a> {
+    /// The stops matched with a station, in the order of the station code
+    pub matched: Vec<(&'a Stop, &'a Station)>,
+
+    /// The stops not matching any station, in the order of the stops
+    pub unmatched_stops: Vec<&'a Stop>,
+
+    /// The stations not matching any stop, in the order of the station code
+    pub unmatched_stations: Vec<&'a Station>,
+}
+
+/// Matches the stops of a GTFS feed with the stations of the API
+///
+/// A stop matches a station when its `stop_id` or otherwise its `stop_code`
+/// is equal (ignoring case) to the [`code`] of the station. Each station is
+/// matched with at most one stop.
+///
+/// [`code`]: Station::code
+pub fn cross_reference<'a>(stops: &'a [Stop], stations: &'a StationResponse) -> CrossReference<'a> {
+    let mut stations: Vec<_> = stations.values().collect();
+    stations.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+
+    let mut matched = Vec::new();
+    let mut unmatched_stations = Vec::new();
+    let mut used = vec![false; stops.len()];
+
+    for station in stations {
+        let code_matches = |code: &str| code.trim().eq_ignore_ascii_case(&station.code);
+        let index = stops
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !used[*index])
+            .find(|(_, stop)| code_matches(&stop.stop_id))
+            .or_else(|| {
+                stops
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !used[*index])
+                    .find(|(_, stop)| stop.stop_code.as_deref().is_some_and(code_matches))
+            })
+            .map(|(index, _)| index);
+
+        match index {
+            Some(index) => {
+                used[index] = true;
+                matched.push((&stops[index], station));
+            }
+            None => unmatched_stations.push(station),
+        }
+    }
+
+    CrossReference {
+        matched,
+        unmatched_stops: stops
+            .iter()
+            .zip(used)
+            .filter_map(|(stop, used)| (!used).then_some(stop))
+            .collect(),
+        unmatched_stations,
+    }
+}
@@ -0,0 +1,610 @@
+//! GTFS Realtime
+//!
+//! Converts a [`TrainResponse`] into
+//! [GTFS Realtime](https://gtfs.org/realtime/) feeds using a
+//! [`FeedGenerator`]. Two feeds can be generated:
+//!
+//! * [`VehiclePosition`] feeds using the location, heading and velocity of
+//!   every train.
+//! * [`TripUpdate`] feeds using the scheduled and estimated arrival and
+//!   departure times of every station of every train.
+//!
+//! The message types of this module are a subset of the official
+//! [`gtfs-realtime.proto`](https://gtfs.org/realtime/proto/) schema and are
+//! encoded using [`prost`]. Fields that cannot be filled using the Amtrak API
+//! are omitted.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use amtrak_api::{
+//!     gtfs_rt::{FeedGenerator, Message},
+//!     Client,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let trains = Client::new().trains().await?;
+//!     let feed = FeedGenerator::new().vehicle_positions(&trains);
+//!
+//!     std::fs::write("vehicle_positions.pb", feed.encode_to_vec())?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::{fmt, sync::Arc};
+
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use chrono_tz::Tz;
+pub use prost::Message;
+
+use crate::responses::{Train, TrainResponse, TrainStation};
+
+/// The version of the GTFS Realtime specification of the generated feeds
+const GTFS_REALTIME_VERSION: &str = "2.0";
+
+/// The number of meters per second in one mile per hour
+const METERS_PER_SECOND_PER_MPH: f32 = 0.44704;
+
+/// The content of a GTFS Realtime feed
+#[derive(Clone, PartialEq, Message)]
+pub struct FeedMessage {
+    /// Metadata about the feed
+    #[prost(message, required, tag = "1")]
+    pub header: FeedHeader,
+
+    /// The contents of the feed
+    #[prost(message, repeated, tag = "2")]
+    pub entity: Vec<FeedEntity>,
+}
+
+/// Metadata about a [`FeedMessage`]
+#[derive(Clone, PartialEq, Message)]
+pub struct FeedHeader {
+    /// The version of the GTFS Realtime specification
+    #[prost(string, required, tag = "1")]
+    pub gtfs_realtime_version: String,
+
+    /// Whether the feed contains the full dataset or only the differences
+    #[prost(enumeration = "feed_header::Incrementality", optional, tag = "2")]
+    pub incrementality: Option<i32>,
+
+    /// The moment the feed was created (in seconds since the Unix epoch)
+    #[prost(uint64, optional, tag = "3")]
+    pub timestamp: Option<u64>,
+}
+
+/// Nested types of [`FeedHeader`]
+pub mod feed_header {
+    /// Whether a feed contains the full dataset or only the differences
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Incrementality {
+        /// The feed contains the full dataset
+        FullDataset = 0,
+
+        /// The feed only contains the differences with the previous feed
+        Differential = 1,
+    }
+}
+
+/// A single entity of a [`FeedMessage`]
+#[derive(Clone, PartialEq, Message)]
+pub struct FeedEntity {
+    /// The unique identifier of the entity within the feed
+    #[prost(string, required, tag = "1")]
+    pub id: String,
+
+    /// Whether the entity is to be deleted (only used by differential feeds)
+    #[prost(bool, optional, tag = "2")]
+    pub is_deleted: Option<bool>,
+
+    /// The realtime progress of a trip
+    #[prost(message, optional, tag = "3")]
+    pub trip_update: Option<TripUpdate>,
+
+    /// The realtime position of a vehicle
+    #[prost(message, optional, tag = "4")]
+    pub vehicle: Option<VehiclePosition>,
+}
+
+/// The realtime progress of a trip along its stops
+#[derive(Clone, PartialEq, Message)]
+pub struct TripUpdate {
+    /// The trip this update applies to
+    #[prost(message, required, tag = "1")]
+    pub trip: TripDescriptor,
+
+    /// The estimated times of the stops of the trip
+    #[prost(message, repeated, tag = "2")]
+    pub stop_time_update: Vec<StopTimeUpdate>,
+
+    /// The vehicle serving the trip
+    #[prost(message, optional, tag = "3")]
+    pub vehicle: Option<VehicleDescriptor>,
+
+    /// The moment the progress was measured (in seconds since the Unix epoch)
+    #[prost(uint64, optional, tag = "4")]
+    pub timestamp: Option<u64>,
+}
+
+/// The estimated time of an arrival or a departure
+#[derive(Clone, PartialEq, Message)]
+pub struct StopTimeEvent {
+    /// The difference (in seconds) between the estimated and scheduled times
+    #[prost(int32, optional, tag = "1")]
+    pub delay: Option<i32>,
+
+    /// The estimated time (in seconds since the Unix epoch)
+    #[prost(int64, optional, tag = "2")]
+    pub time: Option<i64>,
+}
+
+/// The estimated arrival and departure times of a single stop of a trip
+#[derive(Clone, PartialEq, Message)]
+pub struct StopTimeUpdate {
+    /// The GTFS `stop_sequence` of the stop
+    #[prost(uint32, optional, tag = "1")]
+    pub stop_sequence: Option<u32>,
+
+    /// The estimated arrival at the stop
+    #[prost(message, optional, tag = "2")]
+    pub arrival: Option<StopTimeEvent>,
+
+    /// The estimated departure from the stop
+    #[prost(message, optional, tag = "3")]
+    pub departure: Option<StopTimeEvent>,
+
+    /// The GTFS `stop_id` of the stop
+    #[prost(string, optional, tag = "4")]
+    pub stop_id: Option<String>,
+
+    /// The relation between the estimated and scheduled times
+    #[prost(
+        enumeration = "stop_time_update::ScheduleRelationship",
+        optional,
+        tag = "5"
+    )]
+    pub schedule_relationship: Option<i32>,
+}
+
+/// Nested types of [`StopTimeUpdate`]
+pub mod stop_time_update {
+    /// The relation between the estimated and scheduled times of a stop
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum ScheduleRelationship {
+        /// The vehicle is proceeding according to its schedule
+        Scheduled = 0,
+
+        /// The stop is skipped
+        Skipped = 1,
+
+        /// No realtime data is available for the stop
+        NoData = 2,
+
+        /// The vehicle is not following a schedule
+        Unscheduled = 3,
+    }
+}
+
+/// The realtime position of a vehicle
+#[derive(Clone, PartialEq, Message)]
+pub struct VehiclePosition {
+    /// The trip served by the vehicle
+    #[prost(message, optional, tag = "1")]
+    pub trip: Option<TripDescriptor>,
+
+    /// The current position of the vehicle
+    #[prost(message, optional, tag = "2")]
+    pub position: Option<Position>,
+
+    /// The GTFS `stop_sequence` of the stop the vehicle is stopped at or
+    /// travelling to
+    #[prost(uint32, optional, tag = "3")]
+    pub current_stop_sequence: Option<u32>,
+
+    /// The status of the vehicle relative to the current stop
+    #[prost(
+        enumeration = "vehicle_position::VehicleStopStatus",
+        optional,
+        tag = "4"
+    )]
+    pub current_status: Option<i32>,
+
+    /// The moment the position was measured (in seconds since the Unix
+    /// epoch)
+    #[prost(uint64, optional, tag = "5")]
+    pub timestamp: Option<u64>,
+
+    /// The GTFS `stop_id` of the current stop
+    #[prost(string, optional, tag = "7")]
+    pub stop_id: Option<String>,
+
+    /// The vehicle
+    #[prost(message, optional, tag = "8")]
+    pub vehicle: Option<VehicleDescriptor>,
+}
+
+/// Nested types of [`VehiclePosition`]
+pub mod vehicle_position {
+    /// The status of a vehicle relative to its current stop
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum VehicleStopStatus {
+        /// The vehicle is about to arrive at the stop
+        IncomingAt = 0,
+
+        /// The vehicle is stopped at the stop
+        StoppedAt = 1,
+
+        /// The vehicle has departed the previous stop and is in transit
+        InTransitTo = 2,
+    }
+}
+
+/// A geographic position of a vehicle
+#[derive(Clone, PartialEq, Message)]
+pub struct Position {
+    /// The latitude in decimal degrees
+    #[prost(float, required, tag = "1")]
+    pub latitude: f32,
+
+    /// The longitude in decimal degrees
+    #[prost(float, required, tag = "2")]
+    pub longitude: f32,
+
+    /// The bearing in degrees clockwise from north
+    #[prost(float, optional, tag = "3")]
+    pub bearing: Option<f32>,
+
+    /// The speed in meters per second
+    #[prost(float, optional, tag = "5")]
+    pub speed: Option<f32>,
+}
+
+/// Identifies a trip of the static GTFS schedule
+#[derive(Clone, PartialEq, Message)]
+pub struct TripDescriptor {
+    /// The GTFS `trip_id` of the trip
+    #[prost(string, optional, tag = "1")]
+    pub trip_id: Option<String>,
+
+    /// The scheduled start time of the trip (`HH:MM:SS`)
+    #[prost(string, optional, tag = "2")]
+    pub start_time: Option<String>,
+
+    /// The scheduled start date of the trip (`YYYYMMDD`)
+    #[prost(string, optional, tag = "3")]
+    pub start_date: Option<String>,
+
+    /// The GTFS `route_id` of the trip
+    #[prost(string, optional, tag = "5")]
+    pub route_id: Option<String>,
+}
+
+/// Identifies a vehicle
+#[derive(Clone, PartialEq, Message)]
+pub struct VehicleDescriptor {
+    /// The internal identifier of the vehicle
+    #[prost(string, optional, tag = "1")]
+    pub id: Option<String>,
+
+    /// The user visible label of the vehicle
+    #[prost(string, optional, tag = "2")]
+    pub label: Option<String>,
+}
+
+type TrainMapper = Arc<dyn Fn(&Train) -> Option<String> + Send + Sync>;
+type StationMapper = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+type StopSequenceMapper = Arc<dyn Fn(&Train, &TrainStation) -> Option<u32> + Send + Sync>;
+
+/// Generates GTFS Realtime feeds from a [`TrainResponse`]
+///
+/// The identifiers of the Amtrak API do not necessarily match the identifiers
+/// of the static GTFS feed used by the consumer of the realtime feed. By
+/// default:
+///
+/// * The `trip_id` is the [`train_num`] of the train.
+/// * The `route_id` is omitted.
+/// * The `stop_id` is the [`code`] of the station.
+/// * The `stop_sequence` is omitted.
+///
+/// Each of these can be customized using [`trip_id`], [`route_id`],
+/// [`stop_id`] and [`stop_sequence`]. Trains without a `trip_id` are omitted
+/// from the feeds and stations without a `stop_id` are omitted from the trip
+/// updates.
+///
+/// The `start_date` and `start_time` of a trip are expressed in the time zone
+/// of the agency, which is `America/New_York` by default and can be changed
+/// using [`agency_timezone`]. The `start_date` is the calendar date of the
+/// scheduled departure from the origin station in that time zone. The Amtrak
+/// API does not provide the service day of a trip, so a trip scheduled to
+/// depart just after midnight is assigned the following date even if the
+/// static GTFS feed schedules it on the previous service day (using a
+/// departure time past `24:00:00`).
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use amtrak_api::gtfs_rt::FeedGenerator;
+///
+/// let trip_ids = HashMap::from([("2150".to_string(), "252581".to_string())]);
+///
+/// let generator = FeedGenerator::new()
+///     .trip_id(move |train| trip_ids.get(&train.train_num).cloned())
+///     .route_id(|train| Some(train.route_name.clone()))
+///     .stop_id(|code| (code != "XYZ").then(|| code.to_string()));
+/// ```
+///
+/// [`train_num`]: Train::train_num
+/// [`code`]: TrainStation::code
+/// [`trip_id`]: Self::trip_id
+/// [`route_id`]: Self::route_id
+/// [`stop_id`]: Self::stop_id
+/// [`stop_sequence`]: Self::stop_sequence
+/// [`agency_timezone`]: Self::agency_timezone
+#[derive(Clone)]
+pub struct FeedGenerator {
+    trip_id: TrainMapper,
+    route_id: TrainMapper,
+    stop_id: StationMapper,
+    stop_sequence: StopSequenceMapper,
+    agency_timezone: Tz,
+}
+
+impl Default for FeedGenerator {
+    fn default() -> Self {
+        Self {
+            trip_id: Arc::new(|train| Some(train.train_num.clone())),
+            route_id: Arc::new(|_| None),
+            stop_id: Arc::new(|code| Some(code.to_string())),
+            stop_sequence: Arc::new(|_, _| None),
+            agency_timezone: chrono_tz::America::New_York,
+        }
+    }
+}
+
+impl fmt::Debug for FeedGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeedGenerator")
+            .field("agency_timezone", &self.agency_timezone)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FeedGenerator {
+    /// Creates a new generator using the default identifier mapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the mapping of a train to its GTFS `trip_id`
+    ///
+    /// Trains mapped to `None` are omitted from the feeds.
+    pub fn trip_id<F>(mut self, trip_id: F) -> Self
+    where
+        F: Fn(&Train) -> Option<String> + Send + Sync + 'static,
+    {
+        self.trip_id = Arc::new(trip_id);
+        self
+    }
+
+    /// Sets the mapping of a train to its GTFS `route_id`
+    ///
+    /// The `route_id` is omitted for trains mapped to `None`.
+    pub fn route_id<F>(mut self, route_id: F) -> Self
+    where
+        F: Fn(&Train) -> Option<String> + Send + Sync + 'static,
+    {
+        self.route_id = Arc::new(route_id);
+        self
+    }
+
+    /// Sets the mapping of a station code to its GTFS `stop_id`
+    ///
+    /// Stations mapped to `None` are omitted from the trip updates.
+    pub fn stop_id<F>(mut self, stop_id: F) -> Self
+    where
+        F: Fn(&str) -> Option<String> + Send + Sync + 'static,
+    {
+        self.stop_id = Arc::new(stop_id);
+        self
+    }
+
+    /// Sets the mapping of a station of a train to its GTFS `stop_sequence`
+    ///
+    /// The `stop_sequence` is omitted for stations mapped to `None`. The
+    /// position of a station within [`stations`] cannot be used because it
+    /// counts bus stops and stations missing from the static GTFS feed.
+    ///
+    /// [`stations`]: Train::stations
+    pub fn stop_sequence<F>(mut self, stop_sequence: F) -> Self
+    where
+        F: Fn(&Train, &TrainStation) -> Option<u32> + Send + Sync + 'static,
+    {
+        self.stop_sequence = Arc::new(stop_sequence);
+        self
+    }
+
+    /// Sets the time zone of the agency (the `agency_timezone` of the static
+    /// GTFS feed)
+    ///
+    /// The `start_date` and `start_time` of every trip are expressed in this
+    /// time zone.
+    pub fn agency_timezone(mut self, agency_timezone: Tz) -> Self {
+        self.agency_timezone = agency_timezone;
+        self
+    }
+
+    /// Generates a feed containing the [`VehiclePosition`] of every train
+    pub fn vehicle_positions(&self, trains: &TrainResponse) -> FeedMessage {
+        self.feed(trains, |train| {
+            Some(FeedEntity {
+                vehicle: Some(self.vehicle_position(train)?),
+                ..entity(train)
+            })
+        })
+    }
+
+    /// Generates a feed containing the [`TripUpdate`] of every train
+    pub fn trip_updates(&self, trains: &TrainResponse) -> FeedMessage {
+        self.feed(trains, |train| {
+            Some(FeedEntity {
+                trip_update: Some(self.trip_update(train)?),
+                ..entity(train)
+            })
+        })
+    }
+
+    /// Returns the current position of the train
+    ///
+    /// The current stop is the station the train is stopped at or otherwise
+    /// the next station of the train. Returns `None` if the train has no
+    /// `trip_id`.
+    pub fn vehicle_position(&self, train: &Train) -> Option<VehiclePosition> {
+        let (current_status, current_stop) = match train.current_station() {
+            Some(station) => (
+                vehicle_position::VehicleStopStatus::StoppedAt,
+                Some(station),
+            ),
+            None => (
+                vehicle_position::VehicleStopStatus::InTransitTo,
+                train.next_station(),
+            ),
+        };
+
+        let mut vehicle_position = VehiclePosition {
+            trip: Some(self.trip_descriptor(train)?),
+            position: Some(Position {
+                latitude: train.lat as f32,
+                longitude: train.lon as f32,
+                bearing: train.heading.degrees().map(|degrees| degrees as f32),
+                speed: Some(train.velocity * METERS_PER_SECOND_PER_MPH),
+            }),
+            timestamp: Some(timestamp(train.last_value)),
+            vehicle: Some(vehicle_descriptor(train)),
+            ..Default::default()
+        };
+
+        if let Some(station) = current_stop {
+            vehicle_position.current_stop_sequence = (self.stop_sequence)(train, station);
+            vehicle_position.stop_id = (self.stop_id)(&station.code);
+            vehicle_position.set_current_status(current_status);
+        }
+
+        Some(vehicle_position)
+    }
+
+    /// Returns the estimated arrival and departure times of every station of
+    /// the train
+    ///
+    /// Returns `None` if the train has no `trip_id`.
+    pub fn trip_update(&self, train: &Train) -> Option<TripUpdate> {
+        let stop_time_update = train
+            .stations
+            .iter()
+            .filter_map(|station| {
+                let mut update = StopTimeUpdate {
+                    stop_sequence: (self.stop_sequence)(train, station),
+                    arrival: stop_time_event(station.arrival, station.arrival_delay()),
+                    departure: stop_time_event(station.departure, station.departure_delay()),
+                    stop_id: Some((self.stop_id)(&station.code)?),
+                    ..Default::default()
+                };
+
+                update.set_schedule_relationship(
+                    if update.arrival.is_none() && update.departure.is_none() {
+                        stop_time_update::ScheduleRelationship::NoData
+                    } else {
+                        stop_time_update::ScheduleRelationship::Scheduled
+                    },
+                );
+
+                Some(update)
+            })
+            .collect();
+
+        Some(TripUpdate {
+            trip: self.trip_descriptor(train)?,
+            stop_time_update,
+            vehicle: Some(vehicle_descriptor(train)),
+            timestamp: Some(timestamp(train.last_value)),
+        })
+    }
+
+    /// Generates a full dataset feed containing an entity for every train
+    /// in the order of their train id
+    fn feed<F>(&self, trains: &TrainResponse, to_entity: F) -> FeedMessage
+    where
+        F: Fn(&Train) -> Option<FeedEntity>,
+    {
+        let mut trains: Vec<_> = trains.values().flatten().collect();
+        trains.sort_by(|lhs, rhs| lhs.train_id.cmp(&rhs.train_id));
+
+        let mut header = FeedHeader {
+            gtfs_realtime_version: GTFS_REALTIME_VERSION.to_string(),
+            timestamp: Some(timestamp(Utc::now().fixed_offset())),
+            ..Default::default()
+        };
+        header.set_incrementality(feed_header::Incrementality::FullDataset);
+
+        FeedMessage {
+            header,
+            entity: trains.into_iter().filter_map(to_entity).collect(),
+        }
+    }
+
+    /// Returns the trip descriptor of the train
+    ///
+    /// The start date and time are the calendar date and time of the scheduled
+    /// departure from the origin station in the time zone of the agency.
+    fn trip_descriptor(&self, train: &Train) -> Option<TripDescriptor> {
+        let start = train.stations.first().map(|origin| {
+            origin
+                .schedule_departure
+                .with_timezone(&self.agency_timezone)
+        });
+
+        Some(TripDescriptor {
+            trip_id: Some((self.trip_id)(train)?),
+            start_time: start.map(|start| start.format("%H:%M:%S").to_string()),
+            start_date: start.map(|start| start.format("%Y%m%d").to_string()),
+            route_id: (self.route_id)(train),
+        })
+    }
+}
+
+/// Returns an entity identified by the train id of the train
+fn entity(train: &Train) -> FeedEntity {
+    FeedEntity {
+        id: train.train_id.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Returns the vehicle descriptor of the train
+fn vehicle_descriptor(train: &Train) -> VehicleDescriptor {
+    VehicleDescriptor {
+        id: Some(train.train_id.to_string()),
+        label: Some(train.train_num.clone()),
+    }
+}
+
+/// Returns the estimated time of an arrival or a departure
+fn stop_time_event(
+    time: Option<DateTime<FixedOffset>>,
+    delay: Option<Duration>,
+) -> Option<StopTimeEvent> {
+    Some(StopTimeEvent {
+        delay: delay.and_then(|delay| i32::try_from(delay.num_seconds()).ok()),
+        time: Some(time?.timestamp()),
+    })
+}
+
+/// Returns the number of seconds since the Unix epoch
+fn timestamp(time: DateTime<FixedOffset>) -> u64 {
+    time.timestamp().max(0) as u64
+}
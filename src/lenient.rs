@@ -0,0 +1,254 @@
+//! Partial Deserialization
+//!
+//! By default a single malformed entry fails the entire response. The partial
+//! endpoints (such as [`Client::trains_partial`]) and the lenient mode (see
+//! [`ClientBuilder::lenient`]) instead skip malformed entries and report them
+//! as a [`SkippedEntry`].
+//!
+//! [`Client::trains_partial`]: crate::Client::trains_partial
+//! [`ClientBuilder::lenient`]: crate::ClientBuilder::lenient
+
+use std::{fmt, sync::Arc};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use crate::responses::{StationResponse, TrainResponse};
+
+/// Maximum number of bytes of the offending JSON kept in a [`SkippedEntry`]
+const MAX_SNIPPET_LEN: usize = 256;
+
+/// An entry of a response that was skipped because it could not be
+/// deserialized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEntry {
+    /// The key of the entry in the response map
+    ///
+    /// For the `/trains` endpoint this is the [`train_num`] of the skipped
+    /// train and for the `/stations` endpoint this is the [`code`] of the
+    /// skipped station.
+    ///
+    /// [`train_num`]: crate::Train::train_num
+    /// [`code`]: crate::Station::code
+    pub key: String,
+
+    /// The path of the offending field from the root of the response
+    ///
+    /// # Examples:
+    /// * `657[0].stations[3].schArr`
+    /// * `PHL.lat`
+    pub path: String,
+
+    /// The start of the raw JSON of the offending field
+    ///
+    /// When a field is missing this is the JSON of the object that should have
+    /// contained it.
+    pub snippet: String,
+
+    /// The reason the entry could not be deserialized
+    pub error: String,
+}
+
+impl fmt::Display for SkippedEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "skipped entry `{}` at `{}`: {}",
+            self.key, self.path, self.error
+        )
+    }
+}
+
+/// A response where the malformed entries were skipped
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use amtrak_api::Client;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let partial = Client::new().trains_partial().await?;
+///
+///     for skipped in &partial.skipped {
+///         eprintln!("{skipped}");
+///     }
+///
+///     println!("{} trains are being tracked", partial.value.len());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partial<T> {
+    /// The entries that were successfully deserialized
+    pub value: T,
+
+    /// The entries that were skipped
+    pub skipped: Vec<SkippedEntry>,
+}
+
+impl<T> Partial<T> {
+    /// Returns `true` if no entry was skipped
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+/// The callback invoked for every entry skipped in lenient mode
+#[derive(Clone)]
+pub(crate) struct SkipHandler(Arc<dyn Fn(&SkippedEntry) + Send + Sync>);
+
+impl SkipHandler {
+    pub(crate) fn new<F>(on_skipped: F) -> Self
+    where
+        F: Fn(&SkippedEntry) + Send + Sync + 'static,
+    {
+        Self(Arc::new(on_skipped))
+    }
+
+    /// Reports every skipped entry of the partial response and returns the
+    /// successfully deserialized entries
+    pub(crate) fn report<T>(&self, partial: Partial<T>) -> T {
+        partial.skipped.iter().for_each(|entry| (self.0)(entry));
+        partial.value
+    }
+}
+
+impl fmt::Debug for SkipHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SkipHandler").finish_non_exhaustive()
+    }
+}
+
+/// Deserializes the response of the `/trains` or `/trains/{:train_id}`
+/// endpoint, skipping every train that cannot be deserialized
+///
+/// Returns `None` if the response is neither a map nor an array, in which case
+/// the response is not salvageable.
+pub(crate) fn trains(value: Value) -> Option<Partial<TrainResponse>> {
+    let mut partial = Partial {
+        value: TrainResponse::new(),
+        skipped: Vec::new(),
+    };
+
+    for (key, value) in entries(value)? {
+        let values = match value {
+            Value::Array(values) => values,
+            value => {
+                partial
+                    .skipped
+                    .push(skipped(&key, key.clone(), &value, "expected an array"));
+                continue;
+            }
+        };
+
+        let trains: Vec<_> = values
+            .iter()
+            .enumerate()
+            .filter_map(
+                |(index, value)| match entry(&key, format!("{key}[{index}]"), value) {
+                    Ok(train) => Some(train),
+                    Err(skipped) => {
+                        partial.skipped.push(skipped);
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        if !trains.is_empty() {
+            partial.value.insert(key, trains);
+        }
+    }
+
+    Some(partial)
+}
+
+/// Deserializes the response of the `/stations` or `/stations/{:station_code}`
+/// endpoint, skipping every station that cannot be deserialized
+///
+/// Returns `None` if the response is neither a map nor an array, in which case
+/// the response is not salvageable.
+pub(crate) fn stations(value: Value) -> Option<Partial<StationResponse>> {
+    let mut partial = Partial {
+        value: StationResponse::new(),
+        skipped: Vec::new(),
+    };
+
+    for (key, value) in entries(value)? {
+        match entry(&key, key.clone(), &value) {
+            Ok(station) => {
+                partial.value.insert(key, station);
+            }
+            Err(skipped) => partial.skipped.push(skipped),
+        }
+    }
+
+    Some(partial)
+}
+
+/// Returns the entries of a response map
+///
+/// On empty data the Amtrak API serializes an empty array instead of a map.
+fn entries(value: Value) -> Option<serde_json::Map<String, Value>> {
+    match value {
+        Value::Object(map) => Some(map),
+        Value::Array(_) => Some(serde_json::Map::new()),
+        _ => None,
+    }
+}
+
+/// Deserializes a single entry located at `path`
+fn entry<T>(key: &str, path: String, value: &Value) -> Result<T, SkippedEntry>
+where
+    T: DeserializeOwned,
+{
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        let mut path = path;
+        let mut offending = value;
+        let mut found = true;
+
+        for segment in err.path() {
+            match segment {
+                Segment::Seq { index } => path.push_str(&format!("[{index}]")),
+                segment => path.push_str(&format!(".{segment}")),
+            }
+
+            if !found {
+                continue;
+            }
+
+            let child = match segment {
+                Segment::Seq { index } => offending.get(*index),
+                Segment::Map { key } => offending.get(key),
+                _ => None,
+            };
+            match child {
+                Some(child) => offending = child,
+                None => found = false,
+            }
+        }
+
+        skipped(key, path, offending, err.inner())
+    })
+}
+
+/// Creates a [`SkippedEntry`] truncating the snippet of the offending JSON
+fn skipped(key: &str, path: String, offending: &Value, error: impl fmt::Display) -> SkippedEntry {
+    let mut snippet = offending.to_string();
+    if snippet.len() > MAX_SNIPPET_LEN {
+        let mut end = MAX_SNIPPET_LEN;
+        while !snippet.is_char_boundary(end) {
+            end -= 1;
+        }
+        snippet.truncate(end);
+    }
+
+    SkippedEntry {
+        key: key.to_string(),
+        path,
+        snippet,
+        error: error.to_string(),
+    }
+}
@@ -4,7 +4,7 @@
 //! about trains and stations in its network.
 //!
 //! Please check [`Client`] for the various endpoints this API allows you to
-//! call into.
+//! call into and the [`models`] module for the data types they return.
 //!
 //! Note: This library is not affiliated with Amtrak in any way and is an
 //! unofficial implementation of the public facing API. Amtrak is a registered
@@ -37,10 +37,48 @@
 //! }
 //! ```
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod clean;
 mod client;
+mod delay;
+mod drift;
 mod errors;
+mod filter;
+#[cfg(feature = "geo")]
+mod geo;
+mod geojson;
+mod geometry;
+#[cfg(feature = "gtfs")]
+pub mod gtfs;
+#[cfg(feature = "gtfs-rt")]
+pub mod gtfs_rt;
+mod lenient;
+pub mod models;
+mod progress;
+mod rate_limit;
 mod responses;
+mod retry;
+mod timeliness;
+#[cfg(feature = "chrono-tz")]
+mod timezone;
+mod train_id;
+pub mod transport;
 
-pub use client::Client;
-pub use errors::Error;
-pub use responses::{Station, Train, TrainState, TrainStatus};
+pub use clean::Clean;
+pub use client::{Client, ClientBuilder, Result};
+pub use drift::DriftReport;
+pub use errors::{Error, ErrorResponse};
+pub use filter::{BoundingBox, ParseGeoJsonError, Polygon, TrainFilter};
+pub use geojson::GeoJson;
+pub use geometry::{Coordinates, Located, Proximity};
+pub use lenient::{Partial, SkippedEntry};
+#[cfg(feature = "chrono-tz")]
+pub use models::StationTimes;
+pub use models::{
+    DelayTrend, Heading, ParseTrainIdError, Provider, Station, StationResponse, Timeliness,
+    TimelinessComment, Train, TrainId, TrainIdentifier, TrainResponse, TrainState, TrainStation,
+    TrainStatus,
+};
+pub use rate_limit::{RateLimit, RateLimitMode};
+pub use retry::RetryPolicy;
@@ -0,0 +1,56 @@
+//! Data Models
+//!
+//! This module exposes every data type returned by the [`Client`] so that they
+//! can be named in the caller's own function signatures.
+//!
+//! # Stability
+//!
+//! The types in this module follow semantic versioning. Public fields, enum
+//! variants and trait implementations will not be removed, renamed or change
+//! type without a breaking release (a minor version bump while the crate is
+//! still `0.x`). New fields and variants may be added in a compatible release
+//! when the Amtrak API starts providing new data.
+//!
+//! To make this possible, every struct with public fields and every enum of
+//! this module is marked `#[non_exhaustive]`:
+//!
+//! * Structs cannot be built using a struct literal outside of this crate.
+//!   Data models are created by deserializing the JSON of the Amtrak API (see
+//!   [`serde_json::from_str`]), which is also the recommended way to build
+//!   them in tests.
+//! * `match` expressions on enums need a wildcard arm.
+//!
+//! # Example
+//!
+//! ```rust
+//! use amtrak_api::models::{TrainResponse, TrainStation, TrainStatus};
+//!
+//! /// Returns the stations the train is still heading to
+//! fn upcoming_stops(stations: &[TrainStation]) -> Vec<&TrainStation> {
+//!     stations
+//!         .iter()
+//!         .filter(|station| station.status == TrainStatus::Enroute)
+//!         .collect()
+//! }
+//!
+//! /// Returns the number of trains in the response
+//! fn train_count(response: &TrainResponse) -> usize {
+//!     response.values().map(Vec::len).sum()
+//! }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`serde_json::from_str`]: serde_json::from_str
+
+pub use crate::{
+    delay::DelayTrend,
+    responses::{
+        Heading, Provider, Station, StationResponse, Train, TrainResponse, TrainState,
+        TrainStation, TrainStatus,
+    },
+    timeliness::{Timeliness, TimelinessComment},
+    train_id::{ParseTrainIdError, TrainId, TrainIdentifier},
+};
+
+#[cfg(feature = "chrono-tz")]
+pub use crate::timezone::StationTimes;
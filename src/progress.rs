@@ -0,0 +1,128 @@
+//! Train Progress
+//!
+//! Determines where a train is along its route using the [`status`] of each of
+//! its stations and the [`train_state`] of the train.
+//!
+//! Stations served by a connecting bus (see [`bus`]) are skipped since the
+//! train itself does not stop at them. Stations with an
+//! [`Unknown`](TrainStatus::Unknown) status are considered passed when they
+//! come before a station the train has reached and remaining otherwise. Trains
+//! in a [`train_state`] that is not known by this library are located using
+//! the status of their stations.
+//!
+//! [`status`]: TrainStation::status
+//! [`train_state`]: Train::train_state
+//! [`bus`]: TrainStation::bus
+
+use chrono::{DateTime, FixedOffset};
+
+use crate::responses::{Train, TrainState, TrainStation, TrainStatus};
+
+impl Train {
+    /// Returns the station the train is currently stopped at
+    ///
+    /// Returns `None` if the train is between stations, is awaiting departure
+    /// from its origin station or has completed its route.
+    pub fn current_station(&self) -> Option<&TrainStation> {
+        if matches!(
+            self.train_state,
+            TrainState::Predeparture | TrainState::Completed
+        ) {
+            return None;
+        }
+
+        self.stations[..self.passed_stations()]
+            .last()
+            .filter(|station| station.status == TrainStatus::Station && !station.bus)
+    }
+
+    /// Returns the next station the train will stop at
+    ///
+    /// When the train is stopped at a station this returns the station after
+    /// it. Returns `None` if the train has [`Completed`](TrainState::Completed)
+    /// its route.
+    pub fn next_station(&self) -> Option<&TrainStation> {
+        self.remaining_stations().into_iter().next()
+    }
+
+    /// Returns the last station the train departed from
+    ///
+    /// Returns `None` if the train has not departed its origin station yet.
+    pub fn last_departed_station(&self) -> Option<&TrainStation> {
+        if self.train_state == TrainState::Predeparture {
+            return None;
+        }
+
+        self.stations[..self.passed_stations()]
+            .iter()
+            .rev()
+            .find(|station| station.status == TrainStatus::Departed && !station.bus)
+    }
+
+    /// Returns the stations the train has not reached yet in the order the
+    /// train will stop at them
+    ///
+    /// The station the train is currently stopped at is not included.
+    pub fn remaining_stations(&self) -> Vec<&TrainStation> {
+        let passed = match self.train_state {
+            TrainState::Predeparture => 0,
+            TrainState::Completed => self.stations.len(),
+            _ => self.passed_stations(),
+        };
+
+        self.stations[passed..]
+            .iter()
+            .filter(|station| !station.bus)
+            .collect()
+    }
+
+    /// Returns the fraction (between `0.0` and `1.0`) of stations the train
+    /// has reached along its route
+    pub fn completed_fraction(&self) -> f64 {
+        let total = self.stations.iter().filter(|station| !station.bus).count();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let remaining = self.remaining_stations().len();
+        (total - remaining) as f64 / total as f64
+    }
+
+    /// Returns the estimated arrival time of the train at the provided station
+    ///
+    /// Only the [`remaining_stations`] are searched, so this returns `None` if
+    /// the train has already arrived at the station (including the station it
+    /// is currently stopped at), the station is not part of the route or
+    /// Amtrak has not provided an estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `station_code` - The station code to query (see [`code`])
+    ///
+    /// [`remaining_stations`]: Self::remaining_stations
+    /// [`code`]: TrainStation::code
+    pub fn eta_to<S>(&self, station_code: S) -> Option<DateTime<FixedOffset>>
+    where
+        S: AsRef<str>,
+    {
+        let station_code = station_code.as_ref();
+
+        self.remaining_stations()
+            .into_iter()
+            .find(|station| station.code == station_code)?
+            .arrival
+    }
+
+    /// Returns the number of stations (from the start of [`stations`]) the
+    /// train has reached
+    ///
+    /// [`stations`]: Self::stations
+    fn passed_stations(&self) -> usize {
+        self.stations
+            .iter()
+            .rposition(|station| {
+                matches!(station.status, TrainStatus::Departed | TrainStatus::Station)
+            })
+            .map_or(0, |index| index + 1)
+    }
+}
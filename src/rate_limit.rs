@@ -0,0 +1,166 @@
+//! Rate Limiting
+//!
+//! A token bucket rate limiter that can be configured on the [`Client`] to
+//! avoid sending too many requests to the API.
+//!
+//! [`Client`]: crate::Client
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Describes what the client does when the rate limit has been reached
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// Wait until a request is allowed before sending it
+    #[default]
+    Wait,
+
+    /// Immediately return [`Error::RateLimited`] without sending the request
+    ///
+    /// [`Error::RateLimited`]: crate::Error::RateLimited
+    FailFast,
+}
+
+/// The configuration of a token bucket rate limiter
+///
+/// The bucket starts full and holds up to [`burst`] tokens. Every request
+/// consumes one token and tokens are refilled at a steady rate of `requests`
+/// per `period`.
+///
+/// The rate limiter is shared by every clone of the [`Client`] it is configured
+/// on, so cloning a client into multiple tasks does not multiply the budget.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use amtrak_api::{Client, RateLimit};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // Allow a request every 15 seconds with a burst of 4 requests
+///     let client = Client::builder()
+///         .rate_limit(RateLimit::new(1, Duration::from_secs(15)).burst(4))
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [`burst`]: Self::burst
+/// [`Client`]: crate::Client
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    interval: Duration,
+    burst: u32,
+    mode: RateLimitMode,
+}
+
+impl RateLimit {
+    /// Creates a new rate limit allowing `requests` requests per `period`
+    ///
+    /// The burst size defaults to `requests` and the mode defaults to
+    /// [`RateLimitMode::Wait`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `requests` is zero.
+    pub fn new(requests: u32, period: Duration) -> Self {
+        assert!(requests > 0, "requests must be greater than zero");
+
+        Self {
+            interval: period / requests,
+            burst: requests,
+            mode: RateLimitMode::default(),
+        }
+    }
+
+    /// Sets the maximum number of requests that can be sent back to back
+    ///
+    /// The value is clamped to a minimum of `1`.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Sets what the client does when the rate limit has been reached
+    pub fn mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// The state of the token bucket shared by every clone of a client
+#[derive(Debug)]
+struct Bucket {
+    /// The number of available tokens. This can become negative when tokens
+    /// are reserved by callers waiting for their turn.
+    tokens: f64,
+
+    /// The last time the bucket was refilled
+    refilled_at: Instant,
+}
+
+/// A token bucket rate limiter shared between clones of a client
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    config: RateLimit,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimit) -> Self {
+        let bucket = Bucket {
+            tokens: f64::from(config.burst),
+            refilled_at: Instant::now(),
+        };
+
+        Self {
+            config,
+            bucket: Arc::new(Mutex::new(bucket)),
+        }
+    }
+
+    /// Acquires permission to send a single request
+    ///
+    /// Returns how long the caller must wait before sending the request, which
+    /// is zero when a token is immediately available. In
+    /// [`RateLimitMode::FailFast`] the time until a token becomes available is
+    /// instead returned as an error.
+    pub(crate) fn acquire(&self) -> Result<Duration, Duration> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|err| err.into_inner());
+        self.refill(&mut bucket);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(Duration::ZERO);
+        }
+
+        let delay = self.interval_for(1.0 - bucket.tokens);
+        if self.config.mode == RateLimitMode::FailFast {
+            return Err(delay);
+        }
+
+        // Reserve the token now so that waiting callers are served in order
+        bucket.tokens -= 1.0;
+        Ok(delay)
+    }
+
+    /// Adds the tokens accumulated since the last refill
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at);
+
+        bucket.tokens = (bucket.tokens
+            + elapsed.as_secs_f64() / self.config.interval.as_secs_f64())
+        .min(f64::from(self.config.burst));
+        bucket.refilled_at = now;
+    }
+
+    /// Returns how long it takes to refill the provided number of tokens
+    fn interval_for(&self, tokens: f64) -> Duration {
+        self.config.interval.mul_f64(tokens)
+    }
+}
@@ -1,7 +1,9 @@
 use std::{collections::HashMap, fmt};
 
 use chrono::{DateTime, FixedOffset};
-use serde::{de, Deserialize};
+use serde::{de, Deserialize, Serialize};
+
+use crate::{timeliness::TimelinessComment, train_id::TrainId};
 
 /// The response from the `/trains` or `/trains/{:train_id}` endpoint.
 ///
@@ -115,7 +117,8 @@ where
 }
 
 /// Represents an Amtrak train
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Train {
     /// The human readable route name of this train.
     ///
@@ -129,8 +132,8 @@ pub struct Train {
     #[serde(rename = "trainNum")]
     pub train_num: String,
 
-    /// The concatenation of the [`train_num`] with another number (not sure
-    /// what exactly) in the format "{:train_num}-{:instance}".
+    /// The concatenation of the [`train_num`] with an instance number in the
+    /// format "{:train_num}-{:instance}". See [`TrainId`] for more details.
     ///
     /// # Examples:
     /// * `6-4`
@@ -138,7 +141,7 @@ pub struct Train {
     ///
     /// [`train_num`]: Self::train_num
     #[serde(rename = "trainID")]
-    pub train_id: String,
+    pub train_id: TrainId,
 
     /// The current latitude of the train
     pub lat: f64,
@@ -156,8 +159,13 @@ pub struct Train {
     /// * `On Time`
     /// * `Unknown`
     /// * `NaN Minutes Early` (yes really)
+    ///
+    /// The text is parsed into a [`Timeliness`] when the response is
+    /// deserialized and the raw text is preserved.
+    ///
+    /// [`Timeliness`]: crate::Timeliness
     #[serde(rename = "trainTimely")]
-    pub train_timely: String,
+    pub train_timely: TimelinessComment,
 
     /// List of stations that the train will visit. The stations are listed in
     /// the same order the train will stop at each.
@@ -269,7 +277,11 @@ pub struct Train {
 
     /// Unsure of what this field symbolizes.
     ///
-    /// Note: Only provided if provider is "Amtrak"
+    /// Note: Only provided if [`provider`] is [`Provider::Amtrak`], use
+    /// [`amtrak_object_id`] to ignore values provided by other networks.
+    ///
+    /// [`provider`]: Self::provider
+    /// [`amtrak_object_id`]: Self::amtrak_object_id
     #[serde(rename = "objectID")]
     pub object_id: Option<u32>,
 
@@ -278,10 +290,30 @@ pub struct Train {
     /// # Examples:
     /// * `Amtrak`
     /// * `Via`
-    pub provider: String,
+    /// * `Brightline`
+    pub provider: Provider,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl Train {
+    /// Returns the [`object_id`] of this train if it is provided by Amtrak
+    ///
+    /// [`object_id`]: Self::object_id
+    pub fn amtrak_object_id(&self) -> Option<u32> {
+        match self.provider {
+            Provider::Amtrak => self.object_id,
+            _ => None,
+        }
+    }
+}
+
+/// Represents a stop of an Amtrak train
+///
+/// Each [`Train`] lists the stations it will visit in its [`stations`] field
+/// along with the scheduled and actual times of the train at each stop.
+///
+/// [`stations`]: Train::stations
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct TrainStation {
     /// The full human readable name of the station.
     ///
@@ -299,6 +331,8 @@ pub struct TrainStation {
 
     /// The timezone of this station.
     pub tz: Option<String>,
+
+    /// Whether this stop is serviced by a connecting bus instead of the train.
     pub bus: bool,
 
     /// The scheduled arrival time of this train for the current station.
@@ -318,7 +352,7 @@ pub struct TrainStation {
     /// Examples:
     /// `2023-09-05T16:22:00-05:00`
     /// `2023-09-05T15:54:00-05:00`
-    /// `null` or not included in response
+    /// `null` or not included in response (serialized as `null`)
     ///
     /// [`name`]: Self::name
     /// [`code`]: Self::code
@@ -337,7 +371,7 @@ pub struct TrainStation {
     /// Examples:
     /// `2023-09-05T16:22:00-05:00`
     /// `2023-09-05T15:54:00-05:00`
-    /// `null` or not included in response
+    /// `null` or not included in response (serialized as `null`)
     ///
     /// [`name`]: Self::name
     /// [`code`]: Self::code
@@ -358,13 +392,17 @@ pub struct TrainStation {
     /// `On Time`
     /// `NaN Minutes Early` (Yes really)
     ///
+    /// The text is parsed into a [`Timeliness`] when the response is
+    /// deserialized and the raw text is preserved.
+    ///
+    /// [`Timeliness`]: crate::Timeliness
     /// [`name`]: Self::name
     /// [`code`]: Self::code
     /// [`status`]: Self::status
     /// [`Departed`]: TrainStatus::Departed
     /// [`Enroute`]: TrainStatus::Enroute
     #[serde(rename = "arrCmnt")]
-    pub arrival_comment: String,
+    pub arrival_comment: TimelinessComment,
 
     /// A human readable comment on the departure time of this train for the
     /// current station specified by [`name`] or [`code`]. When the
@@ -378,21 +416,34 @@ pub struct TrainStation {
     /// `On Time`
     /// `NaN Minutes Early` (Yes really)
     ///
+    /// The text is parsed into a [`Timeliness`] when the response is
+    /// deserialized and the raw text is preserved.
+    ///
+    /// [`Timeliness`]: crate::Timeliness
     /// [`name`]: Self::name
     /// [`code`]: Self::code
     /// [`status`]: Self::status
     /// [`Departed`]: TrainStatus::Departed
     /// [`Enroute`]: TrainStatus::Enroute
     #[serde(rename = "depCmnt")]
-    pub departure_comment: String,
+    pub departure_comment: TimelinessComment,
 
     /// The current status of this train for the current station specified by
     /// [`name`] or [`code`].
+    ///
+    /// [`name`]: Self::name
+    /// [`code`]: Self::code
     pub status: TrainStatus,
 }
 
 /// Describes a train's heading using cardinal directions
-#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq)]
+///
+/// Any heading that is not known by this library is preserved using the
+/// [`Other`] variant instead of failing the entire response.
+///
+/// [`Other`]: Heading::Other
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Heading {
     /// North heading
     N,
@@ -417,6 +468,10 @@ pub enum Heading {
 
     /// Northwest heading
     NW,
+
+    /// A heading that is not known by this library
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Represents the current status of an Amtrak train being tracked in
@@ -425,10 +480,15 @@ pub enum Heading {
 /// This status can only be applied to a combination of a [`Train`] and a
 /// [`Station`]. It is referenced in the [`stations`] field.
 ///
+/// Any status that is not known by this library is preserved using the
+/// [`Other`] variant instead of failing the entire response.
+///
 /// [`Station`]: Station
 /// [`Train`]: Train
 /// [`stations`]: Train::stations
-#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq)]
+/// [`Other`]: TrainStatus::Other
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TrainStatus {
     /// The train has not yet arrived at the specified station.
     Enroute,
@@ -441,9 +501,20 @@ pub enum TrainStatus {
 
     /// The status of the train is unknown
     Unknown,
+
+    /// A status that is not known by this library
+    #[serde(untagged)]
+    Other(String),
 }
 
-#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq)]
+/// Represents the current state of an Amtrak train along its route
+///
+/// Any state that is not known by this library is preserved using the
+/// [`Other`] variant instead of failing the entire response.
+///
+/// [`Other`]: TrainState::Other
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TrainState {
     /// The train is awaiting departure from its origin station
     Predeparture,
@@ -453,6 +524,111 @@ pub enum TrainState {
 
     /// The train has completed its journey is not longer servicing its route.
     Completed,
+
+    /// A state that is not known by this library
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The network providing the information of a [`Train`]
+///
+/// Besides Amtrak, the API also tracks trains operated by other passenger rail
+/// networks. Any network that is not known by this library is preserved using
+/// the [`Other`] variant.
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::Provider;
+///
+/// assert_eq!(Provider::from("Via"), Provider::Via);
+/// assert_eq!(Provider::from("Metra"), Provider::Other("Metra".to_string()));
+/// assert_eq!(Provider::Brightline.to_string(), "Brightline");
+/// ```
+///
+/// [`Other`]: Provider::Other
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Provider {
+    /// Amtrak (National Railroad Passenger Corporation)
+    Amtrak,
+
+    /// VIA Rail Canada
+    Via,
+
+    /// Brightline
+    Brightline,
+
+    /// A provider that is not known by this library
+    Other(String),
+}
+
+impl Provider {
+    /// Returns the name of the provider as provided by the Amtrak API
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Amtrak => "Amtrak",
+            Self::Via => "Via",
+            Self::Brightline => "Brightline",
+            Self::Other(provider) => provider,
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Provider {
+    fn from(value: &str) -> Self {
+        match value {
+            "Amtrak" => Self::Amtrak,
+            "Via" => Self::Via,
+            "Brightline" => Self::Brightline,
+            _ => Self::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Provider {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Other(_) => Self::Other(value),
+            provider => provider,
+        }
+    }
+}
+
+impl PartialEq<str> for Provider {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Provider {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Serialize for Provider {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Provider {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
 }
 
 /// The response from the `/stations` or `/stations/{:station_code}` endpoint.
@@ -520,7 +696,8 @@ impl<'de> de::Visitor<'de> for StationResponseWrapperVisitor {
 }
 
 /// Represents a unique station that Amtrak services
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Station {
     /// The full human readable name of the station.
     ///
@@ -582,6 +759,9 @@ pub struct Station {
     /// * `19104`
     /// * `10001`
     /// * `L7T 4A8` (Canadian zip code)
+    ///
+    /// Zip codes provided as an integer are converted into (and serialized
+    /// as) a string.
     #[serde(deserialize_with = "deserialize_zip_code")]
     pub zip: String,
 
@@ -0,0 +1,155 @@
+//! Retry Policy
+//!
+//! The retry policy describes which failed requests are retried by the
+//! [`Client`] and how long the client waits in between attempts.
+//!
+//! [`Client`]: crate::Client
+
+use std::time::Duration;
+
+use crate::errors::RequestError;
+
+/// Policy used to retry requests that failed due to a transient error
+///
+/// A request is retried when the connection could not be established, when
+/// the request timed out, or when the API responded with a `5xx` or `429`
+/// status code. Any other failure (such as a `404` or a response that cannot
+/// be deserialized) is returned to the caller immediately.
+///
+/// The delay between attempts grows exponentially starting at
+/// [`base_delay`] and is capped at [`max_delay`]. A random [`jitter`] is
+/// applied to the delay so that many clients do not retry in lockstep. When
+/// the API provides a `Retry-After` header, that delay is used instead.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use amtrak_api::{Client, RetryPolicy};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::builder()
+///         .retry_policy(
+///             RetryPolicy::new()
+///                 .max_attempts(5)
+///                 .base_delay(Duration::from_millis(250))
+///                 .max_delay(Duration::from_secs(10))
+///                 .jitter(0.5),
+///         )
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [`base_delay`]: Self::base_delay
+/// [`max_delay`]: Self::max_delay
+/// [`jitter`]: Self::jitter
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new policy with the default settings
+    ///
+    /// The default policy will make at most 3 attempts, starting with a delay
+    /// of 500 milliseconds capped at 30 seconds, with a jitter of 50%.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of attempts (including the first one) that will
+    /// be made for a single endpoint call
+    ///
+    /// A value of `1` (or `0`) disables retrying.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry
+    ///
+    /// Every subsequent retry doubles the delay until [`max_delay`] is reached.
+    ///
+    /// [`max_delay`]: Self::max_delay
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum delay between two attempts
+    ///
+    /// Note: If the API asks the caller to wait longer than this delay using
+    /// the `Retry-After` header, the request is not retried and the error is
+    /// returned to the caller.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the fraction of the delay that is randomized
+    ///
+    /// A value of `0.0` disables jitter, while a value of `1.0` will pick a
+    /// delay anywhere between zero and the computed delay. The value is clamped
+    /// between `0.0` and `1.0`.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns how long to wait before making the next attempt, or `None` if
+    /// the request should not be retried
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of attempts that have been made so far.
+    /// * `error` - The error returned by the last attempt.
+    pub(crate) fn retry_delay(&self, attempt: u32, error: &RequestError) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match error {
+            RequestError::Request(error) if error.is_connect() || error.is_timeout() => {
+                Some(self.backoff(attempt))
+            }
+            RequestError::Response(response)
+                if response.status == 429 || response.is_server_error() =>
+            {
+                match response.retry_after {
+                    Some(retry_after) if retry_after > self.max_delay => None,
+                    Some(retry_after) => Some(retry_after),
+                    None => Some(self.backoff(attempt)),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Computes the exponential delay (with jitter) after the given attempt
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        delay.mul_f64(1.0 - self.jitter * fastrand::f64())
+    }
+}
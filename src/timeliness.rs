@@ -0,0 +1,245 @@
+//! Timeliness
+//!
+//! The Amtrak API describes how early or late a train is using human readable
+//! text such as `1 Hours, 5 Minutes Late` or `On Time`. This module parses
+//! that text into a structured [`Timeliness`] value.
+
+use std::{fmt, str::FromStr};
+
+use chrono::Duration;
+use serde::{de, Deserialize, Serialize};
+
+/// How early or late a train is (or is predicted to be)
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::Timeliness;
+/// use chrono::Duration;
+///
+/// assert_eq!(
+///     Timeliness::parse("1 Hours, 5 Minutes Late"),
+///     Timeliness::Late(Duration::minutes(65))
+/// );
+/// assert_eq!(Timeliness::parse("On Time"), Timeliness::OnTime);
+/// assert_eq!(Timeliness::parse("NaN Minutes Early"), Timeliness::Unknown);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Timeliness {
+    /// The train is ahead of schedule by the provided duration
+    Early(Duration),
+
+    /// The train is behind schedule by the provided duration
+    Late(Duration),
+
+    /// The train is on schedule
+    OnTime,
+
+    /// The timeliness of the train is unknown or could not be parsed
+    Unknown,
+}
+
+impl Timeliness {
+    /// Parses the human readable text provided by the Amtrak API
+    ///
+    /// Zero delays (such as `0 Minutes Early`) are reported as
+    /// [`Timeliness::OnTime`]. Any text that cannot be understood (such as
+    /// `NaN Minutes Early` or `Unknown`) is reported as
+    /// [`Timeliness::Unknown`].
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+
+        if text.eq_ignore_ascii_case("on time") {
+            return Self::OnTime;
+        }
+
+        let (amount, late) = if let Some(amount) = strip_suffix_ignore_case(text, "late") {
+            (amount, true)
+        } else if let Some(amount) = strip_suffix_ignore_case(text, "early") {
+            (amount, false)
+        } else {
+            return Self::Unknown;
+        };
+
+        let duration = match parse_duration(amount) {
+            Some(duration) => duration,
+            None => return Self::Unknown,
+        };
+
+        if duration.is_zero() {
+            Self::OnTime
+        } else if late {
+            Self::Late(duration)
+        } else {
+            Self::Early(duration)
+        }
+    }
+
+    /// Returns the signed delay of the train
+    ///
+    /// A positive value means the train is late while a negative value means
+    /// the train is early. Returns `None` if the timeliness is unknown.
+    pub fn delay(&self) -> Option<Duration> {
+        match self {
+            Self::Early(duration) => Some(-*duration),
+            Self::Late(duration) => Some(*duration),
+            Self::OnTime => Some(Duration::zero()),
+            Self::Unknown => None,
+        }
+    }
+
+    /// Returns `true` if the train is behind schedule
+    pub fn is_late(&self) -> bool {
+        matches!(self, Self::Late(_))
+    }
+
+    /// Returns `true` if the train is ahead of schedule
+    pub fn is_early(&self) -> bool {
+        matches!(self, Self::Early(_))
+    }
+}
+
+/// Strips the provided suffix (ignoring ASCII case) and trailing whitespace
+fn strip_suffix_ignore_case<'a>(text: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = text.len().checked_sub(suffix.len())?;
+
+    if text.is_char_boundary(split) && text[split..].eq_ignore_ascii_case(suffix) {
+        Some(text[..split].trim_end())
+    } else {
+        None
+    }
+}
+
+/// Parses a duration in the format `X Hours, Y Minutes`
+///
+/// Returns `None` if the duration does not fit in a [`Duration`].
+fn parse_duration(text: &str) -> Option<Duration> {
+    let mut duration = Duration::zero();
+
+    for component in text.split(',') {
+        let mut parts = component.split_whitespace();
+        let value: i64 = parts.next()?.parse().ok()?;
+        let unit = parts.next()?.to_ascii_lowercase();
+
+        if parts.next().is_some() || value < 0 {
+            return None;
+        }
+
+        let component = match unit.trim_end_matches('s') {
+            "day" => Duration::try_days(value)?,
+            "hour" => Duration::try_hours(value)?,
+            "minute" => Duration::try_minutes(value)?,
+            _ => return None,
+        };
+
+        duration = duration.checked_add(&component)?;
+    }
+
+    Some(duration)
+}
+
+/// A human readable timeliness comment along with its parsed [`Timeliness`]
+///
+/// The raw text provided by the Amtrak API is preserved and is used when the
+/// comment is serialized or displayed.
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::{Timeliness, TimelinessComment};
+/// use chrono::Duration;
+///
+/// let comment = TimelinessComment::new("19 Minutes Late");
+/// assert_eq!(comment, "19 Minutes Late");
+/// assert_eq!(comment.timeliness(), Timeliness::Late(Duration::minutes(19)));
+/// assert_eq!(comment.delay(), Some(Duration::minutes(19)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimelinessComment {
+    raw: String,
+    timeliness: Timeliness,
+}
+
+impl TimelinessComment {
+    /// Creates a new comment by parsing the provided text
+    pub fn new(raw: impl Into<String>) -> Self {
+        let raw = raw.into();
+
+        Self {
+            timeliness: Timeliness::parse(&raw),
+            raw,
+        }
+    }
+
+    /// Returns the raw text provided by the Amtrak API
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the parsed timeliness
+    pub fn timeliness(&self) -> Timeliness {
+        self.timeliness
+    }
+
+    /// Returns the signed delay, see [`Timeliness::delay`]
+    pub fn delay(&self) -> Option<Duration> {
+        self.timeliness.delay()
+    }
+}
+
+impl fmt::Display for TimelinessComment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl FromStr for TimelinessComment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<&str> for TimelinessComment {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for TimelinessComment {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PartialEq<str> for TimelinessComment {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl PartialEq<&str> for TimelinessComment {
+    fn eq(&self, other: &&str) -> bool {
+        self.raw == *other
+    }
+}
+
+impl Serialize for TimelinessComment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimelinessComment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
@@ -0,0 +1,131 @@
+//! Time Zones
+//!
+//! The Amtrak API provides every time zone as an IANA time zone name (for
+//! example `America/New_York`) while every timestamp only carries a fixed
+//! UTC offset. This module parses the time zone names into [`Tz`] and renders
+//! the times of a stop in a time zone of the caller's choosing.
+//!
+//! Converting a timestamp only changes how the same instant is displayed, so
+//! the rendered times are correct across daylight saving time transitions
+//! (for example an overnight long distance train crossing a DST boundary).
+
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone};
+use chrono_tz::Tz;
+
+use crate::responses::{Station, Train, TrainStation};
+
+/// Parses an IANA time zone name provided by the Amtrak API
+fn parse_tz(tz: &str) -> Option<Tz> {
+    Tz::from_str(tz.trim()).ok()
+}
+
+/// The scheduled and actual times of a [`TrainStation`] rendered in a specific
+/// time zone
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::TrainStation;
+///
+/// fn print_local_arrival(station: &TrainStation) {
+///     if let Some(times) = station.local_times() {
+///         println!(
+///             "Scheduled to arrive at {} at {}",
+///             station.name,
+///             times.schedule_arrival.format("%H:%M %Z")
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StationTimes<Z: TimeZone> {
+    /// See [`TrainStation::schedule_arrival`]
+    pub schedule_arrival: DateTime<Z>,
+
+    /// See [`TrainStation::schedule_departure`]
+    pub schedule_departure: DateTime<Z>,
+
+    /// See [`TrainStation::arrival`]
+    pub arrival: Option<DateTime<Z>>,
+
+    /// See [`TrainStation::departure`]
+    pub departure: Option<DateTime<Z>>,
+}
+
+impl TrainStation {
+    /// Returns the parsed time zone of this station
+    ///
+    /// Returns `None` if the [`tz`] field is missing or is not a known IANA
+    /// time zone.
+    ///
+    /// [`tz`]: Self::tz
+    pub fn timezone(&self) -> Option<Tz> {
+        self.tz.as_deref().and_then(parse_tz)
+    }
+
+    /// Returns the times of this station rendered in the provided time zone
+    pub fn times_in<Z: TimeZone>(&self, tz: &Z) -> StationTimes<Z> {
+        StationTimes {
+            schedule_arrival: self.schedule_arrival.with_timezone(tz),
+            schedule_departure: self.schedule_departure.with_timezone(tz),
+            arrival: self.arrival.map(|arrival| arrival.with_timezone(tz)),
+            departure: self.departure.map(|departure| departure.with_timezone(tz)),
+        }
+    }
+
+    /// Returns the times of this station rendered in the station's local time
+    /// zone
+    ///
+    /// Returns `None` if the time zone of the station is not known, see
+    /// [`timezone`].
+    ///
+    /// [`timezone`]: Self::timezone
+    pub fn local_times(&self) -> Option<StationTimes<Tz>> {
+        Some(self.times_in(&self.timezone()?))
+    }
+}
+
+impl Train {
+    /// Returns the parsed time zone of the [`origin_tz`] field
+    ///
+    /// [`origin_tz`]: Self::origin_tz
+    pub fn origin_timezone(&self) -> Option<Tz> {
+        self.origin_tz.as_deref().and_then(parse_tz)
+    }
+
+    /// Returns the parsed time zone of the [`destination_tz`] field
+    ///
+    /// [`destination_tz`]: Self::destination_tz
+    pub fn destination_timezone(&self) -> Option<Tz> {
+        self.destination_tz.as_deref().and_then(parse_tz)
+    }
+
+    /// Returns the parsed time zone of the [`event_tz`] field
+    ///
+    /// [`event_tz`]: Self::event_tz
+    pub fn event_timezone(&self) -> Option<Tz> {
+        self.event_tz.as_deref().and_then(parse_tz)
+    }
+
+    /// Returns the times of the provided station rendered in the time zone of
+    /// the train's origin station
+    ///
+    /// Returns `None` if the time zone of the origin station is not known.
+    pub fn origin_times(&self, station: &TrainStation) -> Option<StationTimes<Tz>> {
+        Some(station.times_in(&self.origin_timezone()?))
+    }
+}
+
+impl Station {
+    /// Returns the parsed time zone of this station
+    ///
+    /// Returns `None` if the [`tz`] field is not a known IANA time zone.
+    ///
+    /// [`tz`]: Self::tz
+    pub fn timezone(&self) -> Option<Tz> {
+        parse_tz(&self.tz)
+    }
+}
@@ -0,0 +1,261 @@
+//! Train Identifiers
+//!
+//! Amtrak identifies a single run of a train using the [`train_num`] followed
+//! by an instance number, for example `657-30`.
+//!
+//! [`train_num`]: crate::Train::train_num
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Serialize};
+
+/// The unique identifier of a single run of a train
+///
+/// The identifier is made of the [`train_num`] and an instance number in the
+/// format `{:train_num}-{:instance}`. The instance number distinguishes
+/// multiple runs of the same train number (in practice it matches the day of
+/// the month the train departed its origin station).
+///
+/// Identifiers are ordered by train number (numerically when possible) and
+/// then by instance.
+///
+/// The identifier is kept exactly as provided so that an identifier such as
+/// `657-05` is displayed and serialized back unchanged. Two identifiers are
+/// equal when they are written the same way, so `657-05` and `657-5` are
+/// different identifiers even though they share the same instance.
+///
+/// Identifiers provided by the Amtrak API that do not follow this format
+/// (for example a bare train number) are deserialized without an instance
+/// instead of failing the entire response.
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::TrainId;
+///
+/// let train_id: TrainId = "657-30".parse().unwrap();
+/// assert_eq!(train_id.train_num(), "657");
+/// assert_eq!(train_id.instance(), Some(30));
+/// assert_eq!(train_id.to_string(), "657-30");
+/// ```
+///
+/// [`train_num`]: crate::Train::train_num
+#[derive(Debug, Clone)]
+pub struct TrainId {
+    /// The identifier exactly as provided
+    id: String,
+    train_num: String,
+    instance: Option<u32>,
+}
+
+impl TrainId {
+    /// Creates a new identifier from a train number and an instance number
+    pub fn new(train_num: impl Into<String>, instance: u32) -> Self {
+        let train_num = train_num.into();
+        Self {
+            id: format!("{train_num}-{instance}"),
+            train_num,
+            instance: Some(instance),
+        }
+    }
+
+    /// Returns the train number of this identifier
+    ///
+    /// # Examples:
+    /// * `657`
+    /// * `6`
+    pub fn train_num(&self) -> &str {
+        &self.train_num
+    }
+
+    /// Returns the instance number of this identifier
+    ///
+    /// Returns `None` if the identifier provided by the Amtrak API does not
+    /// contain an instance number.
+    pub fn instance(&self) -> Option<u32> {
+        self.instance
+    }
+}
+
+impl fmt::Display for TrainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+/// The error returned when a string cannot be parsed into a [`TrainId`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid train id `{input}`, expected the format `{{train_num}}-{{instance}}`")]
+pub struct ParseTrainIdError {
+    input: String,
+}
+
+impl FromStr for TrainId {
+    type Err = ParseTrainIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseTrainIdError {
+            input: s.to_string(),
+        };
+
+        let (train_num, instance) = s.rsplit_once('-').ok_or_else(error)?;
+        if train_num.is_empty() {
+            return Err(error());
+        }
+
+        Ok(Self {
+            id: s.to_string(),
+            train_num: train_num.to_string(),
+            instance: Some(instance.parse().map_err(|_| error())?),
+        })
+    }
+}
+
+impl PartialEq for TrainId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TrainId {}
+
+impl Hash for TrainId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Ord for TrainId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let train_num = match (
+            self.train_num.parse::<u64>(),
+            other.train_num.parse::<u64>(),
+        ) {
+            (Ok(lhs), Ok(rhs)) => lhs.cmp(&rhs),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => Ordering::Equal,
+        };
+
+        train_num
+            .then_with(|| self.train_num.cmp(&other.train_num))
+            .then_with(|| self.instance.cmp(&other.instance))
+            .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+impl PartialOrd for TrainId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Serialize for TrainId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TrainId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        // Keep identifiers in an unexpected format as the train number
+        Ok(value.parse().unwrap_or_else(|_| Self {
+            train_num: value.clone(),
+            instance: None,
+            id: value,
+        }))
+    }
+}
+
+/// Identifies the train(s) queried by [`Client::train`]
+///
+/// The `/trains/{:train_id}` endpoint accepts either a full [`TrainId`]
+/// (returning a single run of the train) or a bare train number (returning
+/// every run of the train currently being tracked).
+///
+/// Strings are converted by attempting to parse them as a [`TrainId`] first and
+/// otherwise using them as a train number.
+///
+/// # Example
+///
+/// ```rust
+/// use amtrak_api::{TrainId, TrainIdentifier};
+///
+/// assert_eq!(
+///     TrainIdentifier::from("612-5"),
+///     TrainIdentifier::Id(TrainId::new("612", 5))
+/// );
+/// assert_eq!(
+///     TrainIdentifier::from("612"),
+///     TrainIdentifier::Number("612".to_string())
+/// );
+/// ```
+///
+/// [`Client::train`]: crate::Client::train
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrainIdentifier {
+    /// A single run of a train
+    Id(TrainId),
+
+    /// Every run of a train with this train number
+    Number(String),
+}
+
+impl fmt::Display for TrainIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(train_id) => train_id.fmt(f),
+            Self::Number(train_num) => f.write_str(train_num),
+        }
+    }
+}
+
+impl From<TrainId> for TrainIdentifier {
+    fn from(train_id: TrainId) -> Self {
+        Self::Id(train_id)
+    }
+}
+
+impl From<&TrainId> for TrainIdentifier {
+    fn from(train_id: &TrainId) -> Self {
+        Self::Id(train_id.clone())
+    }
+}
+
+impl From<&str> for TrainIdentifier {
+    fn from(value: &str) -> Self {
+        match value.parse() {
+            Ok(train_id) => Self::Id(train_id),
+            Err(_) => Self::Number(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for TrainIdentifier {
+    fn from(value: String) -> Self {
+        match value.parse() {
+            Ok(train_id) => Self::Id(train_id),
+            Err(_) => Self::Number(value),
+        }
+    }
+}
+
+impl From<&String> for TrainIdentifier {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
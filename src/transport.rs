@@ -0,0 +1,227 @@
+//! HTTP Transport
+//!
+//! The transport is responsible for sending the `GET` requests made by the
+//! [`Client`] and returning the raw response. By default the client uses
+//! [`ReqwestTransport`] (enabled by the `reqwest` feature) but any HTTP stack
+//! can be used by implementing the [`Transport`] trait.
+//!
+//! [`Client`]: crate::Client
+
+use std::{error, fmt, future::Future, pin::Pin, time::Duration};
+
+/// A boxed future returned by a [`Transport`]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// An HTTP stack capable of sending `GET` requests
+///
+/// Implementations should return every response received from the server,
+/// including unsuccessful ones. The [`Client`] is responsible for inspecting
+/// the status code, retrying and deserializing the body.
+///
+/// # Example
+///
+/// A transport that serves a canned response, useful in unit tests.
+///
+/// ```rust
+/// use amtrak_api::{
+///     transport::{BoxFuture, Transport, TransportError},
+///     Client,
+/// };
+///
+/// #[derive(Debug)]
+/// struct EmptyTransport;
+///
+/// impl Transport for EmptyTransport {
+///     fn get<'a>(
+///         &'a self,
+///         _url: &'a str,
+///     ) -> BoxFuture<'a, Result<http::Response<Vec<u8>>, TransportError>> {
+///         Box::pin(async move { Ok(http::Response::new(b"[]".to_vec())) })
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::builder().transport(EmptyTransport).build()?;
+///     assert!(client.trains().await?.is_empty());
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Client`]: crate::Client
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends a `GET` request to the provided url and returns the response
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The full url of the endpoint, including the base url.
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<http::Response<Vec<u8>>, TransportError>>;
+
+    /// Waits for the provided duration
+    ///
+    /// The [`Client`] waits between retries (see [`RetryPolicy`]) and when the
+    /// rate limit has been reached (see [`RateLimit`]). The default
+    /// implementation uses [`tokio::time::sleep`] and therefore requires a
+    /// Tokio runtime with the time driver enabled. Transports running on
+    /// another executor should override this function using the timer of
+    /// their executor.
+    ///
+    /// [`Client`]: crate::Client
+    /// [`RetryPolicy`]: crate::RetryPolicy
+    /// [`RateLimit`]: crate::RateLimit
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// The kind of a [`TransportError`]
+///
+/// The kind is used to decide if a failed request can be retried.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TransportErrorKind {
+    /// The connection to the server could not be established
+    Connect,
+
+    /// The request did not complete in time
+    Timeout,
+
+    /// Any other failure
+    Other,
+}
+
+/// An error returned by a [`Transport`]
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn error::Error + Send + Sync>,
+}
+
+impl TransportError {
+    /// Creates a new error of the provided kind
+    pub fn new<E>(kind: TransportErrorKind, source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            kind,
+            source: source.into(),
+        }
+    }
+
+    /// Creates a new error for a connection that could not be established
+    pub fn connect<E>(source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self::new(TransportErrorKind::Connect, source)
+    }
+
+    /// Creates a new error for a request that did not complete in time
+    pub fn timeout<E>(source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self::new(TransportErrorKind::Timeout, source)
+    }
+
+    /// Creates a new error for any other failure
+    pub fn other<E>(source: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self::new(TransportErrorKind::Other, source)
+    }
+
+    /// Returns the kind of this error
+    pub fn kind(&self) -> TransportErrorKind {
+        self.kind
+    }
+
+    /// Returns `true` if the connection to the server could not be established
+    pub fn is_connect(&self) -> bool {
+        self.kind == TransportErrorKind::Connect
+    }
+
+    /// Returns `true` if the request did not complete in time
+    pub fn is_timeout(&self) -> bool {
+        self.kind == TransportErrorKind::Timeout
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+impl error::Error for TransportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        let kind = if error.is_connect() {
+            TransportErrorKind::Connect
+        } else if error.is_timeout() {
+            TransportErrorKind::Timeout
+        } else {
+            TransportErrorKind::Other
+        };
+
+        Self::new(kind, error)
+    }
+}
+
+/// A [`Transport`] backed by a pooled [`reqwest::Client`]
+///
+/// This is the default transport used by the [`Client`].
+///
+/// [`Client`]: crate::Client
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    http_client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    /// Creates a new transport using the provided [`reqwest::Client`]
+    pub fn new(http_client: reqwest::Client) -> Self {
+        Self { http_client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(http_client: reqwest::Client) -> Self {
+        Self::new(http_client)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    fn get<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> BoxFuture<'a, Result<http::Response<Vec<u8>>, TransportError>> {
+        Box::pin(async move {
+            let response = self.http_client.get(url).send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            let mut http_response = http::Response::new(body);
+            *http_response.status_mut() = status;
+            *http_response.headers_mut() = headers;
+
+            Ok(http_response)
+        })
+    }
+}
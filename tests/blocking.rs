@@ -0,0 +1,57 @@
+#![cfg(all(feature = "blocking", feature = "reqwest"))]
+use amtrak_api::{blocking::Client, ClientBuilder, Error};
+use mockito::Server;
+
+#[test]
+fn test_blocking_station() -> Result<(), Error> {
+    let mut server = Server::new();
+    let mock_server = server
+        .mock("GET", "/stations/ABE")
+        .with_body(
+            r#"
+{
+    "ABE": {
+        "name": "Aberdeen",
+        "code": "ABE",
+        "tz": "America/New_York",
+        "lat": 39.508447,
+        "lon": -76.16326,
+        "address1": "18 East Bel Air Avenue",
+        "address2": " ",
+        "city": "Aberdeen",
+        "state": "MD",
+        "zip": "21001",
+        "trains": []
+    }
+}"#,
+        )
+        .create();
+
+    let client = Client::with_base_url(server.url().as_str());
+    let response = client.station("ABE")?;
+
+    let station = response.get("ABE").unwrap();
+    assert_eq!(station.name, "Aberdeen");
+    assert_eq!(station.zip, "21001");
+
+    mock_server.assert();
+
+    Ok(())
+}
+
+#[test]
+fn test_blocking_error_response() -> Result<(), Error> {
+    let mut server = Server::new();
+    let mock_server = server.mock("GET", "/trains").with_status(503).create();
+
+    let client = ClientBuilder::new()
+        .base_url(server.url().as_str())
+        .build_blocking()?;
+
+    let error = client.trains().unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 503));
+
+    mock_server.assert();
+
+    Ok(())
+}
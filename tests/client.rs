@@ -0,0 +1,89 @@
+#![cfg(feature = "reqwest")]
+use std::time::Duration;
+
+use amtrak_api::{Client, ClientBuilder};
+use mockito::Server;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+#[tokio::test]
+async fn test_builder_user_agent_and_headers() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations")
+        .match_header("user-agent", "departure-board/1.0")
+        .match_header("x-api-team", "operations")
+        .with_body("[]")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-api-team", HeaderValue::from_static("operations"));
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .user_agent("departure-board/1.0")
+        .default_headers(headers)
+        .connect_timeout(Duration::from_secs(5))
+        .read_timeout(Duration::from_secs(5))
+        .build()?;
+
+    // Clones share the same pooled HTTP client and configuration
+    let cloned_client = client.clone();
+
+    assert_eq!(client.stations().await?.len(), 0);
+    assert_eq!(cloned_client.stations().await?.len(), 0);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_default_user_agent() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .match_header(
+            "user-agent",
+            concat!("amtrak-api/", env!("CARGO_PKG_VERSION")),
+        )
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    assert_eq!(client.trains().await?.len(), 0);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_with_http_client() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations/PHL")
+        .match_header("user-agent", "injected-client")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let http_client = reqwest::Client::builder()
+        .user_agent("injected-client")
+        .build()
+        .unwrap();
+
+    let client = ClientBuilder::new()
+        .base_url(server.url().as_str())
+        .http_client(http_client)
+        .user_agent("ignored")
+        .build()?;
+
+    assert_eq!(client.station("PHL").await?.len(), 0);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
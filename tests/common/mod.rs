@@ -0,0 +1,95 @@
+//! Fixtures shared by the integration tests
+//!
+//! Every fixture starts from a complete object as provided by the Amtrak API
+//! and replaces the fields provided by the test.
+
+use amtrak_api::{Station, Train, TrainStation};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+/// A response type that can be built from the JSON of the Amtrak API
+pub trait Fixture: DeserializeOwned {
+    /// Returns the JSON of a complete object
+    fn defaults() -> Value;
+
+    /// Returns the JSON of a complete object with the provided fields replaced
+    fn fixture_json(fields: Value) -> Value {
+        let mut value = Self::defaults();
+        let (Value::Object(object), Value::Object(fields)) = (&mut value, fields) else {
+            panic!("fixture fields must be a JSON object");
+        };
+        object.extend(fields);
+        value
+    }
+
+    /// Deserializes a complete object with the provided fields replaced
+    fn fixture(fields: Value) -> Self {
+        serde_json::from_value(Self::fixture_json(fields)).unwrap()
+    }
+}
+
+impl Fixture for Station {
+    fn defaults() -> Value {
+        json!({
+            "name": "Aberdeen",
+            "code": "ABE",
+            "tz": "America/New_York",
+            "lat": 39.508447,
+            "lon": -76.16326,
+            "address1": "18 East Bel Air Avenue",
+            "address2": " ",
+            "city": "Aberdeen",
+            "state": "MD",
+            "zip": "21001",
+            "trains": []
+        })
+    }
+}
+
+impl Fixture for TrainStation {
+    fn defaults() -> Value {
+        json!({
+            "name": "Middletown",
+            "code": "MID",
+            "tz": "America/New_York",
+            "bus": false,
+            "schArr": "2023-08-29T23:42:00-04:00",
+            "schDep": "2023-08-29T23:42:00-04:00",
+            "arrCmnt": "",
+            "depCmnt": "",
+            "status": "Enroute"
+        })
+    }
+}
+
+impl Fixture for Train {
+    fn defaults() -> Value {
+        json!({
+            "routeName": "Keystone",
+            "trainNum": "657",
+            "trainID": "657-30",
+            "lat": 40.14815944794739,
+            "lon": -76.61796031144218,
+            "trainTimely": "On Time",
+            "stations": [],
+            "heading": "W",
+            "eventCode": "MID",
+            "eventTZ": "America/New_York",
+            "eventName": "Middletown",
+            "origCode": "NYP",
+            "originTZ": "America/New_York",
+            "origName": "New York Penn",
+            "destCode": "HAR",
+            "destTZ": "America/New_York",
+            "destName": "Harrisburg",
+            "trainState": "Active",
+            "velocity": 51.5,
+            "statusMsg": " ",
+            "createdAt": "2023-08-29T23:39:50-04:00",
+            "updatedAt": "2023-08-29T23:39:50-04:00",
+            "lastValTS": "2023-08-29T23:39:34-04:00",
+            "objectID": 847,
+            "provider": "Amtrak"
+        })
+    }
+}
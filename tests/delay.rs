@@ -0,0 +1,106 @@
+mod common;
+
+use amtrak_api::{DelayTrend, Train, TrainStation, TrainStatus};
+use chrono::Duration;
+use common::Fixture;
+use serde_json::{json, Value};
+
+/// Returns the timestamp of the provided time on 2023-08-29
+fn at(time: &str) -> String {
+    format!("2023-08-29T{time}:00-04:00")
+}
+
+/// Returns the JSON of a departed stop arriving and departing at the
+/// provided times
+fn departed(code: &str, schedule: &str, arrival: &str, departure: &str) -> Value {
+    TrainStation::fixture_json(json!({
+        "code": code,
+        "schArr": at(schedule),
+        "schDep": at(schedule),
+        "arr": at(arrival),
+        "dep": at(departure),
+        "status": "Departed"
+    }))
+}
+
+#[test]
+fn test_station_delay() {
+    let station = TrainStation::fixture(json!({
+        "schArr": at("10:00"),
+        "schDep": at("10:05"),
+        "arr": at("10:05"),
+        "dep": at("10:12"),
+        "status": "Departed"
+    }));
+
+    assert_eq!(station.arrival_delay(), Some(Duration::minutes(5)));
+    assert_eq!(station.departure_delay(), Some(Duration::minutes(7)));
+    assert_eq!(station.delay(), Some(Duration::minutes(7)));
+    assert_eq!(station.scheduled_dwell(), Duration::minutes(5));
+    assert_eq!(station.actual_dwell(), Some(Duration::minutes(7)));
+}
+
+#[test]
+fn test_station_early_and_missing_delay() {
+    let station_early = TrainStation::fixture(json!({
+        "schArr": at("10:00"),
+        "schDep": at("10:00"),
+        "arr": at("09:58")
+    }));
+    assert_eq!(station_early.arrival_delay(), Some(Duration::minutes(-2)));
+    assert_eq!(station_early.departure_delay(), None);
+    assert_eq!(station_early.delay(), Some(Duration::minutes(-2)));
+    assert_eq!(station_early.actual_dwell(), None);
+
+    let station_unknown = TrainStation::fixture(json!({ "status": "Unknown" }));
+    assert_eq!(station_unknown.delay(), None);
+}
+
+#[test]
+fn test_train_delay_summary() {
+    let train = Train::fixture(json!({
+        "stations": [
+            departed("NYP", "10:00", "10:00", "10:02"),
+            departed("NWK", "10:15", "10:20", "10:25"),
+            departed("TRE", "11:00", "11:08", "11:08"),
+            TrainStation::fixture_json(json!({
+                "code": "PHL",
+                "schArr": at("12:00"),
+                "schDep": at("12:00"),
+                "arr": at("12:15"),
+                "dep": at("12:20")
+            }))
+        ]
+    }));
+
+    assert_eq!(train.max_delay(), Some(Duration::minutes(20)));
+    assert_eq!(train.last_departed_delay(), Some(Duration::minutes(8)));
+    assert_eq!(train.delay_trend(), Some(DelayTrend::Increasing));
+}
+
+#[test]
+fn test_train_delay_trend() {
+    let decreasing = Train::fixture(json!({
+        "stations": [
+            departed("NYP", "10:00", "10:10", "10:10"),
+            departed("NWK", "10:15", "10:20", "10:20")
+        ]
+    }));
+    assert_eq!(decreasing.delay_trend(), Some(DelayTrend::Decreasing));
+
+    let steady = Train::fixture(json!({
+        "stations": [
+            departed("NYP", "10:00", "10:05", "10:05"),
+            departed("NWK", "10:15", "10:20", "10:20")
+        ]
+    }));
+    assert_eq!(steady.delay_trend(), Some(DelayTrend::Steady));
+
+    let mut predeparture = steady.clone();
+    for station in &mut predeparture.stations {
+        station.status = TrainStatus::Enroute;
+    }
+    assert_eq!(predeparture.last_departed_delay(), None);
+    assert_eq!(predeparture.delay_trend(), None);
+    assert_eq!(predeparture.max_delay(), Some(Duration::minutes(5)));
+}
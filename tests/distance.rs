@@ -0,0 +1,121 @@
+mod common;
+
+use amtrak_api::{Coordinates, Located, Proximity, Station, StationResponse, Train, TrainResponse};
+use common::Fixture;
+use serde_json::json;
+
+fn stations() -> StationResponse {
+    [
+        Station::fixture(json!({ "code": "PHL", "lat": 39.9557, "lon": -75.1822 })),
+        Station::fixture(json!({ "code": "TRE", "lat": 40.2177, "lon": -74.7550 })),
+        Station::fixture(json!({ "code": "NWK", "lat": 40.7347, "lon": -74.1644 })),
+        Station::fixture(json!({ "code": "NYP", "lat": 40.7506, "lon": -73.9935 })),
+        Station::fixture(json!({ "code": "WAS", "lat": 38.8973, "lon": -77.0063 })),
+    ]
+    .into_iter()
+    .map(|station| (station.code.clone(), station))
+    .collect()
+}
+
+fn codes(stations: &[(&Station, f64)]) -> Vec<String> {
+    stations
+        .iter()
+        .map(|(station, _)| station.code.clone())
+        .collect()
+}
+
+#[test]
+fn test_distance_between_located_entities() {
+    let stations = stations();
+    let philadelphia = &stations["PHL"];
+    let new_york = &stations["NYP"];
+
+    let distance = philadelphia.distance_to(new_york);
+    assert!((distance - 134.0).abs() < 0.1);
+    assert!((new_york.distance_to(philadelphia) - distance).abs() < 1e-9);
+    assert_eq!(philadelphia.distance_to(philadelphia), 0.0);
+
+    let train = Train::fixture(json!({ "lat": 39.9557, "lon": -75.1822 }));
+    assert_eq!(train.distance_to(philadelphia), 0.0);
+    assert_eq!(train.distance_to(&Coordinates::new(39.9557, -75.1822)), 0.0);
+
+    // A quarter of the earth's circumference
+    let pole = Coordinates::new(90.0, 0.0);
+    let equator = Coordinates::new(0.0, 123.0);
+    assert!((pole.distance_to(&equator) - 10_007.5).abs() < 0.1);
+}
+
+#[test]
+fn test_nearest_stations() {
+    let stations = stations();
+    let newark_airport = Coordinates::new(40.7045, -74.1907);
+
+    let nearest = stations.nearest(&newark_airport, 3);
+    assert_eq!(codes(&nearest), ["NWK", "NYP", "TRE"]);
+    assert!(nearest.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+    assert_eq!(stations.nearest(&newark_airport, 10).len(), 5);
+    assert!(stations.nearest(&newark_airport, 0).is_empty());
+    assert!(StationResponse::new()
+        .nearest(&newark_airport, 1)
+        .is_empty());
+}
+
+#[test]
+fn test_stations_within_radius() {
+    let stations = stations();
+    let city_hall = Coordinates::new(39.9526, -75.1635);
+
+    let within = stations.within_radius(&city_hall, 50.0);
+    assert_eq!(codes(&within), ["PHL", "TRE"]);
+    assert!(within.iter().all(|(_, distance)| *distance <= 50.0));
+
+    assert!(stations.within_radius(&city_hall, 1.0).is_empty());
+    assert_eq!(stations.within_radius(&city_hall, 1_000.0).len(), 5);
+}
+
+#[test]
+fn test_train_nearest_station() {
+    let stations = stations();
+
+    let train = Train::fixture(json!({ "lat": 40.1901, "lon": -74.8071 }));
+    let (station, distance) = train.nearest_station(&stations).unwrap();
+    assert_eq!(station.code, "TRE");
+    assert!(distance < 10.0);
+
+    assert!(train.nearest_station(&StationResponse::new()).is_none());
+}
+
+#[test]
+fn test_nearest_trains() {
+    let trains = TrainResponse::from([
+        (
+            "170".to_string(),
+            vec![Train::fixture(
+                json!({ "trainNum": "170", "lat": 40.1901, "lon": -74.8071 }),
+            )],
+        ),
+        (
+            "171".to_string(),
+            vec![
+                Train::fixture(json!({ "trainNum": "171", "lat": 39.0, "lon": -76.9 })),
+                Train::fixture(json!({ "trainNum": "171", "lat": 40.7400, "lon": -74.1000 })),
+            ],
+        ),
+    ]);
+
+    let nearest = trains.nearest(&stations()["NYP"], 2);
+    let train_ids: Vec<_> = nearest
+        .iter()
+        .map(|(train, _)| (train.train_num.as_str(), train.lat))
+        .collect();
+    assert_eq!(train_ids, [("171", 40.7400), ("170", 40.1901)]);
+
+    let positions = [
+        Coordinates::new(40.7506, -73.9935),
+        Coordinates::new(38.8973, -77.0063),
+    ];
+    let within = positions.within_radius(&stations()["WAS"], 1.0);
+    assert_eq!(within.len(), 1);
+    assert_eq!(within[0].0, &positions[1]);
+}
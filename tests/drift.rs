@@ -0,0 +1,83 @@
+#![cfg(feature = "reqwest")]
+mod common;
+
+use amtrak_api::{Client, Station, Train, TrainStation};
+use common::Fixture;
+use mockito::Server;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_trains_drift() -> Result<(), amtrak_api::Error> {
+    let body = json!({
+        "657": [Train::fixture_json(json!({
+            "stations": [TrainStation::fixture_json(json!({ "arr": null, "platform": "3" }))],
+            "alerts": []
+        }))]
+    });
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let (response, report) = client.trains_with_drift().await?;
+
+    assert_eq!(response["657"].len(), 1);
+    assert!(!report.is_empty());
+    assert_eq!(
+        report.unknown_fields.keys().collect::<Vec<_>>(),
+        vec!["Train.alerts", "TrainStation.platform"]
+    );
+    assert_eq!(
+        report.missing_fields.keys().collect::<Vec<_>>(),
+        vec!["TrainStation.dep"]
+    );
+    assert_eq!(
+        report.null_fields.keys().collect::<Vec<_>>(),
+        vec!["TrainStation.arr"]
+    );
+    assert_eq!(report.unknown_fields["Train.alerts"], 1);
+    assert!(report
+        .to_string()
+        .contains("unknown field `TrainStation.platform` (1 occurrences)"));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stations_drift() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let matching_mock = server
+        .mock("GET", "/stations")
+        .with_body(json!({ "ABE": Station::fixture_json(json!({})) }).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let empty_mock = server
+        .mock("GET", "/stations")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    let (response, report) = client.stations_with_drift().await?;
+    assert_eq!(response["ABE"], Station::fixture(json!({})));
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "no schema drift");
+
+    let (response, report) = client.stations_with_drift().await?;
+    assert!(response.is_empty());
+    assert!(report.is_empty());
+
+    matching_mock.assert_async().await;
+    empty_mock.assert_async().await;
+
+    Ok(())
+}
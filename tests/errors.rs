@@ -0,0 +1,151 @@
+#![cfg(feature = "reqwest")]
+use std::time::Duration;
+
+use amtrak_api::{Client, Error};
+use mockito::Server;
+
+#[tokio::test]
+async fn test_not_found_response() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains/ABC")
+        .with_status(404)
+        .with_body("Not Found")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let error = client.train("ABC").await.unwrap_err();
+
+    match error {
+        Error::ApiErrorResponse(response) => {
+            assert_eq!(response.status, 404);
+            assert_eq!(response.retry_after, None);
+            assert_eq!(response.body, "Not Found");
+            assert!(response.is_client_error());
+            assert!(!response.is_server_error());
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_too_many_requests_response() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_status(429)
+        .with_header("Retry-After", "120")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let error = client.trains().await.unwrap_err();
+
+    match error {
+        Error::ApiErrorResponse(response) => {
+            assert_eq!(response.status, 429);
+            assert_eq!(response.retry_after, Some(Duration::from_secs(120)));
+            assert!(response.body.is_empty());
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_server_error_response() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations")
+        .with_status(503)
+        .with_header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT")
+        .with_body("x".repeat(2048))
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let error = client.stations().await.unwrap_err();
+
+    match error {
+        Error::ApiErrorResponse(response) => {
+            assert_eq!(response.status, 503);
+            // A date in the past means the caller can retry immediately
+            assert_eq!(response.retry_after, Some(Duration::ZERO));
+            assert_eq!(response.body.len(), 512);
+            assert!(response.is_server_error());
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_malformed_response() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations/PHL")
+        .with_body(r#"{"PHL": {"code": "PHL"}}"#)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .deserialize_diagnostics(false)
+        .build()?;
+    let error = client.station("PHL").await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::DeserializeFailed {
+            path: None,
+            response: None,
+            ..
+        }
+    ));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_malformed_response_diagnostics() -> Result<(), Error> {
+    let body = r#"{"PHL": {"code": "PHL", "lat": "north"}}"#;
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations/PHL")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .deserialize_diagnostics(true)
+        .build()?;
+    let error = client.station("PHL").await.unwrap_err();
+
+    match &error {
+        Error::DeserializeFailed { path, response, .. } => {
+            assert_eq!(path.as_deref(), Some("PHL.lat"));
+            assert_eq!(response.as_deref(), Some(body));
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+    assert!(error.to_string().contains("(at `PHL.lat`)"));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+mod common;
+
+use amtrak_api::{
+    BoundingBox, Coordinates, Polygon, Train, TrainFilter, TrainResponse, TrainState,
+};
+use common::Fixture;
+use serde_json::json;
+
+const NORTHEAST_CORRIDOR: &str = r#"{
+    "type": "FeatureCollection",
+    "features": [
+        {
+            "type": "Feature",
+            "properties": { "name": "Northeast Corridor" },
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [
+                    [[-77.2, 38.7], [-73.5, 40.4], [-70.9, 42.5], [-72.0, 42.5], [-74.5, 41.2], [-77.5, 39.2], [-77.2, 38.7]],
+                    [[-74.3, 40.3], [-74.1, 40.3], [-74.1, 40.5], [-74.3, 40.5], [-74.3, 40.3]]
+                ]
+            }
+        },
+        {
+            "type": "Feature",
+            "properties": { "name": "Station" },
+            "geometry": { "type": "Point", "coordinates": [-75.1822, 39.9557] }
+        }
+    ]
+}"#;
+
+fn trains() -> TrainResponse {
+    [
+        json!({
+            "trainNum": "170",
+            "routeName": "Northeast Regional",
+            "lat": 40.2177,
+            "lon": -74.7550
+        }),
+        json!({ "trainNum": "2150", "routeName": "Acela", "lat": 39.9557, "lon": -75.1822 }),
+        json!({
+            "trainNum": "2151",
+            "routeName": "Acela",
+            "trainState": "Predeparture",
+            "lat": 40.7506,
+            "lon": -73.9935
+        }),
+        json!({ "trainNum": "5", "routeName": "California Zephyr", "lat": 41.8789, "lon": -87.6359 }),
+        json!({ "trainNum": "43", "routeName": "Pennsylvanian", "lat": 40.2659, "lon": -76.8837 }),
+    ]
+    .into_iter()
+    .map(Train::fixture)
+    .map(|train| (train.train_num.clone(), vec![train]))
+    .collect()
+}
+
+fn train_nums(trains: &[&Train]) -> Vec<String> {
+    let mut train_nums: Vec<_> = trains.iter().map(|train| train.train_num.clone()).collect();
+    train_nums.sort();
+    train_nums
+}
+
+#[test]
+fn test_bounding_box() {
+    let bounding_box =
+        BoundingBox::new(Coordinates::new(42.5, -70.9), Coordinates::new(38.7, -77.2));
+    assert_eq!(bounding_box.south_west, Coordinates::new(38.7, -77.2));
+    assert_eq!(bounding_box.north_east, Coordinates::new(42.5, -70.9));
+
+    assert!(bounding_box.contains(&Coordinates::new(40.0, -75.0)));
+    assert!(bounding_box.contains(&Coordinates::new(38.7, -77.2)));
+    assert!(!bounding_box.contains(&Coordinates::new(41.8789, -87.6359)));
+    assert!(!bounding_box.contains(&Coordinates::new(43.0, -75.0)));
+}
+
+#[test]
+fn test_polygon_contains() {
+    let triangle = Polygon::new(vec![
+        Coordinates::new(0.0, 0.0),
+        Coordinates::new(0.0, 10.0),
+        Coordinates::new(10.0, 0.0),
+    ]);
+    assert!(triangle.contains(&Coordinates::new(2.0, 2.0)));
+    assert!(!triangle.contains(&Coordinates::new(6.0, 6.0)));
+    assert!(!triangle.contains(&Coordinates::new(-1.0, 2.0)));
+
+    let corridor: Polygon = NORTHEAST_CORRIDOR.parse().unwrap();
+    assert!(corridor.contains(&Coordinates::new(39.9557, -75.1822)));
+    assert!(corridor.contains(&Coordinates::new(40.7506, -73.9935)));
+    assert!(!corridor.contains(&Coordinates::new(41.8789, -87.6359)));
+    // Inside the hole of the polygon
+    assert!(!corridor.contains(&Coordinates::new(40.4, -74.2)));
+}
+
+#[test]
+fn test_polygon_from_geojson() {
+    let multi_polygon = Polygon::from_geojson(
+        r#"{
+            "type": "MultiPolygon",
+            "coordinates": [
+                [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]],
+                [[[5.0, 5.0], [6.0, 5.0], [6.0, 6.0], [5.0, 6.0], [5.0, 5.0]]]
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert!(multi_polygon.contains(&Coordinates::new(0.5, 0.5)));
+    assert!(multi_polygon.contains(&Coordinates::new(5.5, 5.5)));
+    assert!(!multi_polygon.contains(&Coordinates::new(3.0, 3.0)));
+
+    for invalid in [
+        "not json",
+        r#"{ "coordinates": [] }"#,
+        r#"{ "type": "Point", "coordinates": [0.0, 0.0] }"#,
+        r#"{ "type": "Polygon", "coordinates": [[[0.0], [1.0, 1.0]]] }"#,
+        r#"{ "type": "Polygon", "coordinates": [[["0.0", 0.0]]] }"#,
+        r#"{ "type": "FeatureCollection", "features": {} }"#,
+    ] {
+        let err = invalid.parse::<Polygon>().unwrap_err();
+        assert!(err.to_string().starts_with("invalid GeoJSON polygon: "));
+    }
+}
+
+#[test]
+fn test_train_filter() {
+    let trains = trains();
+    let corridor: Polygon = NORTHEAST_CORRIDOR.parse().unwrap();
+
+    assert_eq!(TrainFilter::new().matching(&trains).len(), 5);
+
+    let filter = TrainFilter::new().polygon(corridor.clone());
+    assert_eq!(
+        train_nums(&filter.matching(&trains)),
+        ["170", "2150", "2151"]
+    );
+
+    let filter = TrainFilter::new()
+        .polygon(corridor.clone())
+        .state(TrainState::Active);
+    assert_eq!(train_nums(&filter.matching(&trains)), ["170", "2150"]);
+
+    let filter = TrainFilter::new()
+        .polygon(corridor)
+        .route("Acela")
+        .route("Pennsylvanian");
+    assert_eq!(train_nums(&filter.matching(&trains)), ["2150", "2151"]);
+
+    let filter = TrainFilter::new().bounding_box(BoundingBox::new(
+        Coordinates::new(39.0, -77.0),
+        Coordinates::new(41.0, -74.5),
+    ));
+    assert_eq!(train_nums(&filter.matching(&trains)), ["170", "2150", "43"]);
+}
+
+#[test]
+fn test_train_filter_apply() {
+    let filter = TrainFilter::new()
+        .bounding_box(BoundingBox::new(
+            Coordinates::new(38.7, -77.2),
+            Coordinates::new(42.5, -70.9),
+        ))
+        .route("Acela");
+
+    let trains = filter.apply(trains());
+    let mut train_nums: Vec<_> = trains.keys().cloned().collect();
+    train_nums.sort();
+    assert_eq!(train_nums, ["2150", "2151"]);
+    assert!(filter.apply(TrainResponse::new()).is_empty());
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "geo")]
+mod common;
+
+use amtrak_api::{Coordinates, Station, StationResponse, Train, TrainStation};
+use common::Fixture;
+use geo_types::{coord, point, LineString, Point};
+use serde_json::json;
+
+fn stations() -> StationResponse {
+    [
+        Station::fixture(json!({ "code": "PHL", "lat": 39.9557, "lon": -75.1822 })),
+        Station::fixture(json!({ "code": "TRE", "lat": 40.2177, "lon": -74.7550 })),
+        Station::fixture(json!({ "code": "NYP", "lat": 40.7506, "lon": -73.9935 })),
+    ]
+    .into_iter()
+    .map(|station| (station.code.clone(), station))
+    .collect()
+}
+
+fn train() -> Train {
+    Train::fixture(json!({
+        "lat": 40.0871,
+        "lon": -74.9550,
+        "stations": [
+            TrainStation::fixture_json(json!({ "code": "PHL" })),
+            TrainStation::fixture_json(json!({ "code": "XYZ" })),
+            TrainStation::fixture_json(json!({ "code": "TRE" })),
+            TrainStation::fixture_json(json!({ "code": "NYP" }))
+        ]
+    }))
+}
+
+#[test]
+fn test_point_conversions() {
+    let stations = stations();
+    let train = train();
+
+    assert_eq!(
+        Point::from(&stations["PHL"]),
+        point!(x: -75.1822, y: 39.9557)
+    );
+    assert_eq!(Point::from(&train), point!(x: -74.9550, y: 40.0871));
+
+    let coordinates = Coordinates::new(40.7506, -73.9935);
+    let point = Point::from(coordinates);
+    assert_eq!(point.x(), -73.9935);
+    assert_eq!(point.y(), 40.7506);
+    assert_eq!(Coordinates::from(point), coordinates);
+    assert_eq!(
+        Coordinates::from(coord! { x: -73.9935, y: 40.7506 }),
+        coordinates
+    );
+}
+
+#[test]
+fn test_train_station_point() {
+    let stations = stations();
+    let train = train();
+
+    assert_eq!(
+        train.stations[2].point(&stations),
+        Some(point!(x: -74.7550, y: 40.2177))
+    );
+    assert_eq!(train.stations[1].point(&stations), None);
+}
+
+#[test]
+fn test_train_line_string() {
+    let stations = stations();
+
+    assert_eq!(
+        train().line_string(&stations),
+        LineString::from(vec![
+            (-75.1822, 39.9557),
+            (-74.7550, 40.2177),
+            (-73.9935, 40.7506),
+        ])
+    );
+    assert_eq!(
+        train().line_string(&StationResponse::new()),
+        LineString::new(Vec::new())
+    );
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use amtrak_api::{GeoJson, Station, StationResponse, Train, TrainResponse, TrainStation};
+use common::Fixture;
+use serde_json::json;
+
+fn stations() -> StationResponse {
+    [
+        json!({
+            "code": "PHL",
+            "name": "Philadelphia",
+            "lat": 39.9557,
+            "lon": -75.1822,
+            "city": "Philadelphia",
+            "state": "PA"
+        }),
+        json!({ "code": "TRE", "name": "Trenton", "lat": 40.2177, "lon": -74.7550 }),
+    ]
+    .into_iter()
+    .map(Station::fixture)
+    .map(|station| (station.code.clone(), station))
+    .collect()
+}
+
+fn trains() -> TrainResponse {
+    let stops = |codes: &[&str]| -> Vec<_> {
+        codes
+            .iter()
+            .map(|code| TrainStation::fixture_json(json!({ "code": code })))
+            .collect()
+    };
+
+    [
+        json!({
+            "trainNum": "643",
+            "trainID": "643-29",
+            "lat": 40.0871,
+            "lon": -74.9550,
+            "trainTimely": "5 Minutes Late",
+            "stations": stops(&["PHL", "XYZ", "TRE"])
+        }),
+        json!({
+            "trainNum": "600",
+            "trainID": "600-29",
+            "lat": 39.9557,
+            "lon": -75.1822,
+            "trainTimely": "NaN Minutes Early",
+            "stations": stops(&["PHL", "XYZ"])
+        }),
+    ]
+    .into_iter()
+    .map(Train::fixture)
+    .map(|train| (train.train_num.clone(), vec![train]))
+    .collect()
+}
+
+#[test]
+fn test_trains_geojson() {
+    let trains = trains();
+    let geojson = serde_json::to_value(GeoJson::new(&trains)).unwrap();
+
+    assert_eq!(
+        geojson,
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-75.1822, 39.9557] },
+                    "properties": {
+                        "route_name": "Keystone",
+                        "train_id": "600-29",
+                        "train_num": "600",
+                        "train_timely": "NaN Minutes Early",
+                        "train_timely_minutes": null,
+                        "velocity": 51.5,
+                        "heading": "W",
+                        "train_state": "Active",
+                        "provider": "Amtrak"
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-74.9550, 40.0871] },
+                    "properties": {
+                        "route_name": "Keystone",
+                        "train_id": "643-29",
+                        "train_num": "643",
+                        "train_timely": "5 Minutes Late",
+                        "train_timely_minutes": 5,
+                        "velocity": 51.5,
+                        "heading": "W",
+                        "train_state": "Active",
+                        "provider": "Amtrak"
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_trains_geojson_with_routes() {
+    let trains = trains();
+    let stations = stations();
+    let geojson = serde_json::to_value(GeoJson::new(&trains).with_routes(&stations)).unwrap();
+
+    let features = geojson["features"].as_array().unwrap();
+    // Train 600 only has a single located station so it has no route
+    assert_eq!(features.len(), 3);
+    assert_eq!(
+        features[2],
+        json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [[-75.1822, 39.9557], [-74.7550, 40.2177]]
+            },
+            "properties": {
+                "route_name": "Keystone",
+                "train_id": "643-29"
+            }
+        })
+    );
+}
+
+#[test]
+fn test_stations_geojson() {
+    let stations = stations();
+    let geojson = serde_json::to_value(GeoJson::new(&stations)).unwrap();
+
+    assert_eq!(geojson["type"], "FeatureCollection");
+    assert_eq!(
+        geojson["features"][0],
+        json!({
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [-75.1822, 39.9557] },
+            "properties": {
+                "code": "PHL",
+                "name": "Philadelphia",
+                "tz": "America/New_York",
+                "city": "Philadelphia",
+                "state": "PA"
+            }
+        })
+    );
+    assert_eq!(geojson["features"][1]["properties"]["code"], "TRE");
+
+    let empty = serde_json::to_value(GeoJson::new(&StationResponse::new())).unwrap();
+    assert_eq!(
+        empty,
+        json!({ "type": "FeatureCollection", "features": [] })
+    );
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use amtrak_api::{Coordinates, Heading, Located, Station, StationResponse, Train, TrainStation};
+use common::Fixture;
+use serde_json::json;
+
+const HEADINGS: [Heading; 8] = [
+    Heading::N,
+    Heading::NE,
+    Heading::E,
+    Heading::SE,
+    Heading::S,
+    Heading::SW,
+    Heading::W,
+    Heading::NW,
+];
+
+fn stations() -> StationResponse {
+    [
+        Station::fixture(json!({ "code": "PHL", "lat": 39.9557, "lon": -75.1822 })),
+        Station::fixture(json!({ "code": "TRE", "lat": 40.2177, "lon": -74.7550 })),
+        Station::fixture(json!({ "code": "NYP", "lat": 40.7506, "lon": -73.9935 })),
+    ]
+    .into_iter()
+    .map(|station| (station.code.clone(), station))
+    .collect()
+}
+
+fn northbound_train(heading: &str) -> Train {
+    Train::fixture(json!({
+        "lat": 40.0871,
+        "lon": -74.9550,
+        "heading": heading,
+        "stations": [
+            TrainStation::fixture_json(json!({ "code": "PHL", "status": "Departed" })),
+            TrainStation::fixture_json(json!({ "code": "TRE" })),
+            TrainStation::fixture_json(json!({ "code": "NYP" }))
+        ]
+    }))
+}
+
+#[test]
+fn test_heading_degrees() {
+    for (index, heading) in HEADINGS.iter().enumerate() {
+        let degrees = heading.degrees().unwrap();
+        assert_eq!(degrees, index as f64 * 45.0);
+        assert_eq!(Heading::from_degrees(degrees).as_ref(), Some(heading));
+        assert_eq!(
+            Heading::from_degrees(degrees + 360.0).as_ref(),
+            Some(heading)
+        );
+        assert_eq!(
+            Heading::from_degrees(degrees + 22.0).as_ref(),
+            Some(heading)
+        );
+    }
+
+    assert_eq!(Heading::from_degrees(22.5), Some(Heading::NE));
+    assert_eq!(Heading::from_degrees(f64::INFINITY), None);
+    assert_eq!(Heading::Other("Unknown".to_string()).degrees(), None);
+}
+
+#[test]
+fn test_heading_unit_vector_and_display() {
+    let (east, north) = Heading::E.unit_vector().unwrap();
+    assert!((east - 1.0).abs() < 1e-9);
+    assert!(north.abs() < 1e-9);
+
+    let (east, north) = Heading::SW.unit_vector().unwrap();
+    assert!((east + 0.5_f64.sqrt()).abs() < 1e-9);
+    assert!((north + 0.5_f64.sqrt()).abs() < 1e-9);
+
+    let names: Vec<String> = HEADINGS.iter().map(ToString::to_string).collect();
+    assert_eq!(names, ["N", "NE", "E", "SE", "S", "SW", "W", "NW"]);
+    assert_eq!(Heading::Other("Unknown".to_string()).to_string(), "Unknown");
+}
+
+#[test]
+fn test_heading_angular_difference() {
+    assert_eq!(Heading::N.angular_difference(0.0), Some(0.0));
+    assert_eq!(Heading::N.angular_difference(350.0), Some(10.0));
+    assert_eq!(Heading::NW.angular_difference(45.0), Some(90.0));
+    assert_eq!(Heading::E.angular_difference(-90.0), Some(180.0));
+    assert_eq!(
+        Heading::Other("Unknown".to_string()).angular_difference(0.0),
+        None
+    );
+}
+
+#[test]
+fn test_bearing_between_stations() {
+    let stations = stations();
+    let train = northbound_train("NE");
+
+    let bearing = train.stations[0]
+        .bearing_to(&train.stations[2], &stations)
+        .unwrap();
+    assert!((bearing - 48.3).abs() < 0.1);
+
+    let reverse = stations["NYP"]
+        .coordinates()
+        .bearing_to(&stations["PHL"].coordinates());
+    assert!((reverse - 229.1).abs() < 0.1);
+
+    assert_eq!(
+        train.stations[0].coordinates(&stations),
+        Some(Coordinates::new(39.9557, -75.1822))
+    );
+    assert!(train.stations[0]
+        .bearing_to(&train.stations[1], &StationResponse::new())
+        .is_none());
+}
+
+#[test]
+fn test_train_heading_deviation() {
+    let stations = stations();
+
+    let train = northbound_train("NE");
+    let bearing = train.bearing_to_next_station(&stations).unwrap();
+    assert_eq!(Heading::from_degrees(bearing), Some(Heading::NE));
+    assert!(train.heading_deviation(&stations).unwrap() < 22.5);
+
+    let train = northbound_train("SW");
+    assert!(train.heading_deviation(&stations).unwrap() > 157.5);
+
+    let train = northbound_train("Unknown");
+    assert!(train.heading_deviation(&stations).is_none());
+}
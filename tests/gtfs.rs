@@ -0,0 +1,127 @@
+#![cfg(feature = "gtfs")]
+mod common;
+
+use amtrak_api::{
+    gtfs::{self, Agency, Stop},
+    Station, StationResponse,
+};
+use common::Fixture;
+use serde_json::json;
+
+fn stations() -> StationResponse {
+    [
+        json!({ "code": "TRE", "name": "Trenton", "lat": 40.2177, "lon": -74.755 }),
+        json!({
+            "code": "PHL",
+            "name": "Philadelphia, 30th Street",
+            "lat": 39.9557,
+            "lon": -75.1822
+        }),
+        json!({ "code": "NYP", "name": "New York Penn", "lat": 40.7506, "lon": -73.9935 }),
+    ]
+    .into_iter()
+    .map(Station::fixture)
+    .map(|station| (station.code.clone(), station))
+    .collect()
+}
+
+#[test]
+fn test_write_stops() {
+    let mut stops = Vec::new();
+    gtfs::write_stops(&stations(), &mut stops).unwrap();
+
+    assert_eq!(
+        String::from_utf8(stops).unwrap(),
+        "stop_id,stop_code,stop_name,stop_lat,stop_lon,stop_timezone\n\
+         NYP,,New York Penn,40.7506,-73.9935,America/New_York\n\
+         PHL,,\"Philadelphia, 30th Street\",39.9557,-75.1822,America/New_York\n\
+         TRE,,Trenton,40.2177,-74.755,America/New_York\n"
+    );
+}
+
+#[test]
+fn test_write_agency() {
+    let mut agency = Vec::new();
+    gtfs::write_agency(&[Agency::default()], &mut agency).unwrap();
+
+    assert_eq!(
+        String::from_utf8(agency).unwrap(),
+        "agency_id,agency_name,agency_url,agency_timezone,agency_lang,agency_phone\n\
+         ,Amtrak,https://www.amtrak.com,America/New_York,en,1-800-872-7245\n"
+    );
+}
+
+#[test]
+fn test_read_stops() {
+    let stops = gtfs::read_stops(
+        "stop_id,stop_code,stop_name,stop_desc,stop_lat,stop_lon,stop_timezone\n\
+         PHL,, Philadelphia 30th Street ,,39.9557,-75.1822,America/New_York\n\
+         1234,nyp,New York Penn,,,,\n"
+            .as_bytes(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        stops,
+        [
+            Stop {
+                stop_id: "PHL".to_string(),
+                stop_code: None,
+                stop_name: Some("Philadelphia 30th Street".to_string()),
+                stop_lat: Some(39.9557),
+                stop_lon: Some(-75.1822),
+                stop_timezone: Some("America/New_York".to_string()),
+            },
+            Stop {
+                stop_id: "1234".to_string(),
+                stop_code: Some("nyp".to_string()),
+                stop_name: Some("New York Penn".to_string()),
+                stop_lat: None,
+                stop_lon: None,
+                stop_timezone: None,
+            },
+        ]
+    );
+
+    let minimal = gtfs::read_stops("stop_id\nPHL\n".as_bytes()).unwrap();
+    assert_eq!(minimal[0].stop_id, "PHL");
+    assert_eq!(minimal[0].stop_name, None);
+
+    assert!(gtfs::read_stops("stop_name\nTrenton\n".as_bytes()).is_err());
+    assert!(gtfs::read_stops("stop_id,stop_lat\nPHL,north\n".as_bytes()).is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    let stations = stations();
+    let mut stops = Vec::new();
+    gtfs::write_stops(&stations, &mut stops).unwrap();
+
+    let stops = gtfs::read_stops(stops.as_slice()).unwrap();
+    assert_eq!(stops[1], Stop::from(&stations["PHL"]));
+}
+
+#[test]
+fn test_cross_reference() {
+    let stations = stations();
+    let stops = gtfs::read_stops(
+        "stop_id,stop_code,stop_name\n\
+         phl,,Philadelphia\n\
+         1234,NYP,New York Penn\n\
+         BOS,,Boston South Station\n"
+            .as_bytes(),
+    )
+    .unwrap();
+
+    let cross_reference = gtfs::cross_reference(&stops, &stations);
+
+    let matched: Vec<_> = cross_reference
+        .matched
+        .iter()
+        .map(|(stop, station)| (stop.stop_id.as_str(), station.code.as_str()))
+        .collect();
+    assert_eq!(matched, [("1234", "NYP"), ("phl", "PHL")]);
+
+    assert_eq!(cross_reference.unmatched_stops, [&stops[2]]);
+    assert_eq!(cross_reference.unmatched_stations, [&stations["TRE"]]);
+}
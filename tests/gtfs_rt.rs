@@ -0,0 +1,286 @@
+#![cfg(feature = "gtfs-rt")]
+mod common;
+
+use std::collections::HashMap;
+
+use amtrak_api::{
+    gtfs_rt::{
+        feed_header::Incrementality, stop_time_update::ScheduleRelationship,
+        vehicle_position::VehicleStopStatus, FeedGenerator, FeedMessage, Message, StopTimeEvent,
+    },
+    Train, TrainResponse, TrainStation,
+};
+use chrono::DateTime;
+use common::Fixture;
+use serde_json::json;
+
+fn train(train_num: &str, statuses: [&str; 3]) -> Train {
+    let stop = |code: &str, schedule: &str, actual: &str, status: &str| {
+        let mut stop = TrainStation::fixture_json(json!({
+            "code": code,
+            "schArr": schedule,
+            "schDep": schedule,
+            "status": status
+        }));
+        if !status.is_empty() {
+            stop["arr"] = json!(actual);
+            stop["dep"] = json!(actual);
+        }
+        stop
+    };
+
+    Train::fixture(json!({
+        "routeName": "Northeast Regional",
+        "trainNum": train_num,
+        "trainID": format!("{train_num}-29"),
+        "lat": 40.0871,
+        "lon": -74.9550,
+        "trainTimely": "5 Minutes Late",
+        "heading": "NE",
+        "velocity": 100.0,
+        "updatedAt": "2023-08-29T10:06:00-04:00",
+        "lastValTS": "2023-08-29T10:06:00-04:00",
+        "stations": [
+            stop(
+                "PHL",
+                "2023-08-29T10:00:00-04:00",
+                "2023-08-29T10:05:00-04:00",
+                statuses[0]
+            ),
+            stop(
+                "TRE",
+                "2023-08-29T10:30:00-04:00",
+                "2023-08-29T10:34:00-04:00",
+                statuses[1]
+            ),
+            stop(
+                "NYP",
+                "2023-08-29T11:30:00-04:00",
+                "2023-08-29T11:29:00-04:00",
+                statuses[2]
+            )
+        ]
+    }))
+}
+
+/// Collects the trains into a response keyed by train number
+fn response<const N: usize>(trains: [Train; N]) -> TrainResponse {
+    trains
+        .into_iter()
+        .map(|train| (train.train_num.clone(), vec![train]))
+        .collect()
+}
+
+fn trains() -> TrainResponse {
+    response([
+        train("170", ["Departed", "Enroute", "Enroute"]),
+        train("66", ["Departed", "Station", "Enroute"]),
+    ])
+}
+
+fn last_value() -> u64 {
+    DateTime::parse_from_rfc3339("2023-08-29T10:06:00-04:00")
+        .unwrap()
+        .timestamp() as u64
+}
+
+#[test]
+fn test_vehicle_positions() {
+    let feed = FeedGenerator::new().vehicle_positions(&trains());
+
+    assert_eq!(feed.header.gtfs_realtime_version, "2.0");
+    assert_eq!(feed.header.incrementality(), Incrementality::FullDataset);
+    assert!(feed.header.timestamp.is_some());
+
+    let ids: Vec<_> = feed
+        .entity
+        .iter()
+        .map(|entity| entity.id.as_str())
+        .collect();
+    assert_eq!(ids, ["66-29", "170-29"]);
+    assert!(feed
+        .entity
+        .iter()
+        .all(|entity| entity.trip_update.is_none()));
+
+    let stopped = feed.entity[0].vehicle.as_ref().unwrap();
+    assert_eq!(stopped.current_status(), VehicleStopStatus::StoppedAt);
+    assert_eq!(stopped.current_stop_sequence, None);
+    assert_eq!(stopped.stop_id.as_deref(), Some("TRE"));
+
+    let moving = feed.entity[1].vehicle.as_ref().unwrap();
+    assert_eq!(moving.current_status(), VehicleStopStatus::InTransitTo);
+    assert_eq!(moving.current_stop_sequence, None);
+    assert_eq!(moving.timestamp, Some(last_value()));
+
+    let trip = moving.trip.as_ref().unwrap();
+    assert_eq!(trip.trip_id.as_deref(), Some("170"));
+    assert_eq!(trip.route_id, None);
+    assert_eq!(trip.start_date.as_deref(), Some("20230829"));
+    assert_eq!(trip.start_time.as_deref(), Some("10:00:00"));
+
+    let position = moving.position.as_ref().unwrap();
+    assert_eq!(position.latitude, 40.0871);
+    assert_eq!(position.longitude, -74.9550);
+    assert_eq!(position.bearing, Some(45.0));
+    assert!((position.speed.unwrap() - 44.704).abs() < 1e-3);
+
+    let vehicle = moving.vehicle.as_ref().unwrap();
+    assert_eq!(vehicle.id.as_deref(), Some("170-29"));
+    assert_eq!(vehicle.label.as_deref(), Some("170"));
+}
+
+#[test]
+fn test_trip_updates() {
+    let feed = FeedGenerator::new().trip_updates(&trains());
+    let trip_update = feed.entity[1].trip_update.as_ref().unwrap();
+
+    assert!(feed.entity.iter().all(|entity| entity.vehicle.is_none()));
+    assert_eq!(trip_update.trip.trip_id.as_deref(), Some("170"));
+    assert_eq!(trip_update.timestamp, Some(last_value()));
+    assert_eq!(trip_update.stop_time_update.len(), 3);
+
+    let departed = &trip_update.stop_time_update[0];
+    assert_eq!(departed.stop_sequence, None);
+    assert_eq!(departed.stop_id.as_deref(), Some("PHL"));
+    assert_eq!(
+        departed.schedule_relationship(),
+        ScheduleRelationship::Scheduled
+    );
+    assert_eq!(
+        departed.departure,
+        Some(StopTimeEvent {
+            delay: Some(300),
+            time: Some(last_value() as i64 - 60),
+        })
+    );
+
+    let early = &trip_update.stop_time_update[2];
+    assert_eq!(early.arrival.as_ref().unwrap().delay, Some(-60));
+}
+
+#[test]
+fn test_trip_updates_without_estimates() {
+    let trains = response([train("170", ["", "", ""])]);
+
+    let feed = FeedGenerator::new().trip_updates(&trains);
+    let stop_time_update = &feed.entity[0]
+        .trip_update
+        .as_ref()
+        .unwrap()
+        .stop_time_update;
+
+    assert!(stop_time_update.iter().all(|update| {
+        update.arrival.is_none()
+            && update.departure.is_none()
+            && update.schedule_relationship() == ScheduleRelationship::NoData
+    }));
+}
+
+#[test]
+fn test_trip_start_in_agency_timezone() {
+    let origin = TrainStation::fixture_json(json!({
+        "code": "CHI",
+        "tz": "America/Chicago",
+        "schArr": "2023-08-29T23:30:00-05:00",
+        "schDep": "2023-08-29T23:30:00-05:00",
+        "status": "Departed"
+    }));
+    let trains = response([Train::fixture(json!({
+        "trainNum": "29",
+        "trainID": "29-29",
+        "lat": 41.8789,
+        "lon": -87.6359,
+        "origCode": "CHI",
+        "originTZ": "America/Chicago",
+        "origName": "Chicago Union Station",
+        "stations": [origin]
+    }))]);
+
+    let feed = FeedGenerator::new().trip_updates(&trains);
+    let trip = &feed.entity[0].trip_update.as_ref().unwrap().trip;
+    assert_eq!(trip.start_date.as_deref(), Some("20230830"));
+    assert_eq!(trip.start_time.as_deref(), Some("00:30:00"));
+
+    // Express the trip start in the time zone of its origin instead
+    let origin_timezone = trains["29"][0].origin_timezone().unwrap();
+    assert_eq!(origin_timezone, chrono_tz::America::Chicago);
+
+    let feed = FeedGenerator::new()
+        .agency_timezone(origin_timezone)
+        .trip_updates(&trains);
+    let trip = &feed.entity[0].trip_update.as_ref().unwrap().trip;
+    assert_eq!(trip.start_date.as_deref(), Some("20230829"));
+    assert_eq!(trip.start_time.as_deref(), Some("23:30:00"));
+}
+
+#[test]
+fn test_trip_start_after_midnight() {
+    // The start date is the calendar date of the departure rather than the
+    // service day of the trip
+    let origin = TrainStation::fixture_json(json!({
+        "code": "NYP",
+        "schArr": "2023-08-30T00:15:00-04:00",
+        "schDep": "2023-08-30T00:15:00-04:00",
+        "status": "Departed"
+    }));
+    let trains = response([Train::fixture(json!({ "stations": [origin] }))]);
+
+    let feed = FeedGenerator::new().trip_updates(&trains);
+    let trip = &feed.entity[0].trip_update.as_ref().unwrap().trip;
+    assert_eq!(trip.start_date.as_deref(), Some("20230830"));
+    assert_eq!(trip.start_time.as_deref(), Some("00:15:00"));
+}
+
+#[test]
+fn test_custom_mapping() {
+    // The trip of the static GTFS feed does not stop at Trenton
+    let stop_sequences = HashMap::from([("PHL", 1), ("NYP", 2)]);
+
+    let generator = FeedGenerator::new()
+        .trip_id(|train| (train.train_num == "170").then(|| "252581".to_string()))
+        .route_id(|train| Some(train.route_name.to_lowercase().replace(' ', "-")))
+        .stop_id(|code| (code != "TRE").then(|| format!("AMTK-{code}")))
+        .stop_sequence(move |_, station| stop_sequences.get(station.code.as_str()).copied());
+
+    let feed = generator.trip_updates(&trains());
+    assert_eq!(feed.entity.len(), 1);
+
+    let trip_update = feed.entity[0].trip_update.as_ref().unwrap();
+    assert_eq!(trip_update.trip.trip_id.as_deref(), Some("252581"));
+    assert_eq!(
+        trip_update.trip.route_id.as_deref(),
+        Some("northeast-regional")
+    );
+
+    let stops: Vec<_> = trip_update
+        .stop_time_update
+        .iter()
+        .map(|update| {
+            (
+                update.stop_sequence.unwrap(),
+                update.stop_id.as_deref().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(stops, [(1, "AMTK-PHL"), (2, "AMTK-NYP")]);
+
+    let feed = generator.vehicle_positions(&trains());
+    let vehicle = feed.entity[0].vehicle.as_ref().unwrap();
+    assert_eq!(vehicle.stop_id, None);
+    assert_eq!(vehicle.current_stop_sequence, None);
+}
+
+#[test]
+fn test_encode_decode() {
+    let generator = FeedGenerator::new();
+
+    for feed in [
+        generator.vehicle_positions(&trains()),
+        generator.trip_updates(&trains()),
+        generator.trip_updates(&TrainResponse::new()),
+    ] {
+        let decoded = FeedMessage::decode(feed.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, feed);
+    }
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "reqwest")]
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use amtrak_api::{
+    Client, Heading, SkippedEntry, Station, Train, TrainState, TrainStation, TrainStatus,
+};
+use common::Fixture;
+use mockito::Server;
+use serde_json::json;
+
+#[test]
+fn test_unknown_enum_variants() -> serde_json::Result<()> {
+    let train = Train::fixture(json!({ "heading": "Unknown", "trainState": "Delayed" }));
+    assert_eq!(train.heading, Heading::Other("Unknown".to_string()));
+    assert_eq!(train.train_state, TrainState::Other("Delayed".to_string()));
+
+    // Unknown variants are serialized back to the original value
+    let value = serde_json::to_value(&train)?;
+    assert_eq!(value["heading"], "Unknown");
+    assert_eq!(value["trainState"], "Delayed");
+
+    let status: TrainStatus = serde_json::from_str("\"Cancelled\"")?;
+    assert_eq!(status, TrainStatus::Other("Cancelled".to_string()));
+    let status: TrainStatus = serde_json::from_str("\"Unknown\"")?;
+    assert_eq!(status, TrainStatus::Unknown);
+
+    let station: Result<TrainStation, _> = serde_json::from_value(json!({ "status": 5 }));
+    assert!(station.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_mode_skips_malformed_trains() -> Result<(), amtrak_api::Error> {
+    let malformed = Train::fixture_json(json!({ "trainNum": "658", "lat": "not a number" }));
+
+    let body = json!({
+        "657": [Train::fixture_json(json!({}))],
+        "658": [malformed],
+        "659": "not an array",
+    });
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body(body.to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let skipped = Arc::new(Mutex::new(Vec::<SkippedEntry>::new()));
+    let reported = skipped.clone();
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .lenient(move |entry| reported.lock().unwrap().push(entry.clone()))
+        .build()?;
+
+    let response = client.trains().await?;
+    assert_eq!(response.len(), 1);
+    assert_eq!(response["657"][0].train_num, "657");
+
+    let mut skipped = skipped.lock().unwrap().clone();
+    skipped.sort_by(|lhs, rhs| lhs.key.cmp(&rhs.key));
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].key, "658");
+    assert_eq!(skipped[0].path, "658[0].lat");
+    assert!(skipped[0].error.contains("invalid type"));
+    assert_eq!(skipped[1].key, "659");
+    assert_eq!(skipped[1].path, "659");
+
+    // Without the lenient mode the entire response fails
+    let client = Client::with_base_url(server.url().as_str());
+    assert!(matches!(
+        client.trains().await,
+        Err(amtrak_api::Error::DeserializeFailed { .. })
+    ));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_mode_empty_response() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains/657")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .lenient(|entry| panic!("unexpected skipped entry {entry}"))
+        .build()?;
+    assert!(client.train("657").await?.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_trains_partial() -> Result<(), amtrak_api::Error> {
+    let malformed = Train::fixture_json(json!({
+        "trainNum": "658",
+        "stations": [{ "name": "Harrisburg", "code": "HAR", "bus": "no" }]
+    }));
+
+    let body = json!({
+        "657": [Train::fixture_json(json!({})), malformed.clone()],
+        "658": [malformed],
+    });
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let partial = client.trains_partial().await?;
+
+    assert!(!partial.is_complete());
+    assert_eq!(partial.value.len(), 1);
+    assert_eq!(partial.value["657"].len(), 1);
+
+    let mut skipped = partial.skipped;
+    skipped.sort_by(|lhs, rhs| lhs.key.cmp(&rhs.key));
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].key, "657");
+    assert_eq!(skipped[0].path, "657[1].stations[0].bus");
+    assert_eq!(skipped[0].snippet, "\"no\"");
+    assert_eq!(skipped[1].path, "658[0].stations[0].bus");
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stations_partial() -> Result<(), amtrak_api::Error> {
+    let body = json!({
+        "ABE": Station::fixture_json(json!({ "zip": 21001 })),
+        "PHL": {
+            "name": "Philadelphia",
+            "code": "PHL"
+        }
+    });
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations")
+        .with_body(body.to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    let partial = client.stations_partial().await?;
+
+    assert_eq!(partial.value.len(), 1);
+    assert_eq!(partial.value["ABE"].zip, "21001");
+    assert_eq!(partial.skipped.len(), 1);
+    assert_eq!(partial.skipped[0].key, "PHL");
+    assert_eq!(partial.skipped[0].path, "PHL");
+    assert!(partial.skipped[0].error.contains("missing field"));
+    assert!(partial.skipped[0].snippet.contains("Philadelphia"));
+
+    // The lenient mode applies to stations too
+    let skipped = Arc::new(Mutex::new(0));
+    let reported = skipped.clone();
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .lenient(move |_| *reported.lock().unwrap() += 1)
+        .build()?;
+    assert_eq!(client.stations().await?.len(), 1);
+    assert_eq!(*skipped.lock().unwrap(), 1);
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_partial_unsalvageable_response() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body("\"maintenance\"")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+    assert!(matches!(
+        client.trains_partial().await,
+        Err(amtrak_api::Error::DeserializeFailed { .. })
+    ));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
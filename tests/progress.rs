@@ -0,0 +1,99 @@
+mod common;
+
+use amtrak_api::{Train, TrainStation};
+use chrono::DateTime;
+use common::Fixture;
+use serde_json::{json, Value};
+
+/// Returns the JSON of a stop arriving and departing at 10:05
+fn stop(code: &str, status: &str) -> Value {
+    TrainStation::fixture_json(json!({
+        "code": code,
+        "arr": "2023-08-29T10:05:00-04:00",
+        "dep": "2023-08-29T10:05:00-04:00",
+        "status": status
+    }))
+}
+
+fn codes(stations: Vec<&TrainStation>) -> Vec<&str> {
+    stations
+        .into_iter()
+        .map(|station| station.code.as_str())
+        .collect()
+}
+
+#[test]
+fn test_progress_between_stations() {
+    let mut bus = stop("ABE", "Enroute");
+    bus["bus"] = json!(true);
+
+    let train = Train::fixture(json!({
+        "stations": [
+            stop("NYP", "Departed"),
+            stop("NWK", "Unknown"),
+            stop("TRE", "Departed"),
+            bus,
+            stop("PHL", "Enroute"),
+            stop("HAR", "Unknown")
+        ]
+    }));
+
+    assert!(train.current_station().is_none());
+    assert_eq!(train.last_departed_station().unwrap().code, "TRE");
+    assert_eq!(train.next_station().unwrap().code, "PHL");
+    assert_eq!(codes(train.remaining_stations()), vec!["PHL", "HAR"]);
+    assert_eq!(train.completed_fraction(), 0.6);
+}
+
+#[test]
+fn test_progress_at_station() {
+    let train = Train::fixture(json!({
+        "stations": [
+            stop("NYP", "Departed"),
+            stop("TRE", "Station"),
+            stop("PHL", "Enroute"),
+            stop("HAR", "Enroute")
+        ]
+    }));
+
+    assert_eq!(train.current_station().unwrap().code, "TRE");
+    assert_eq!(train.last_departed_station().unwrap().code, "NYP");
+    assert_eq!(train.next_station().unwrap().code, "PHL");
+    assert_eq!(codes(train.remaining_stations()), vec!["PHL", "HAR"]);
+    assert_eq!(train.completed_fraction(), 0.5);
+
+    // The train already arrived at the station it is stopped at
+    let eta = DateTime::parse_from_rfc3339("2023-08-29T10:05:00-04:00").unwrap();
+    assert_eq!(train.eta_to("TRE"), None);
+    assert_eq!(train.eta_to("PHL"), Some(eta));
+    assert_eq!(train.eta_to("HAR"), Some(eta));
+    assert_eq!(train.eta_to("NYP"), None);
+    assert_eq!(train.eta_to("XYZ"), None);
+}
+
+#[test]
+fn test_progress_train_state() {
+    let stations = json!([stop("NYP", "Enroute"), stop("PHL", "Enroute")]);
+
+    let predeparture = Train::fixture(json!({
+        "trainState": "Predeparture",
+        "stations": stations
+    }));
+    assert!(predeparture.current_station().is_none());
+    assert!(predeparture.last_departed_station().is_none());
+    assert_eq!(predeparture.next_station().unwrap().code, "NYP");
+    assert_eq!(predeparture.completed_fraction(), 0.0);
+
+    let completed = Train::fixture(json!({
+        "trainState": "Completed",
+        "stations": stations
+    }));
+    assert!(completed.current_station().is_none());
+    assert!(completed.next_station().is_none());
+    assert!(completed.remaining_stations().is_empty());
+    assert_eq!(completed.completed_fraction(), 1.0);
+
+    let empty = Train::fixture(json!({}));
+    assert!(empty.next_station().is_none());
+    assert_eq!(empty.completed_fraction(), 0.0);
+}
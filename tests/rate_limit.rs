@@ -0,0 +1,71 @@
+#![cfg(feature = "reqwest")]
+use std::time::{Duration, Instant};
+
+use amtrak_api::{Client, Error, RateLimit, RateLimitMode};
+use mockito::Server;
+
+#[tokio::test]
+async fn test_rate_limit_fail_fast_shared_by_clones() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations")
+        .with_body("[]")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .rate_limit(
+            RateLimit::new(1, Duration::from_secs(60))
+                .burst(2)
+                .mode(RateLimitMode::FailFast),
+        )
+        .build()?;
+    let cloned_client = client.clone();
+
+    assert_eq!(client.stations().await?.len(), 0);
+    assert_eq!(cloned_client.stations().await?.len(), 0);
+
+    // The budget is shared so the clone cannot send a third request
+    let error = cloned_client.stations().await.unwrap_err();
+    match error {
+        Error::RateLimited { retry_after } => {
+            assert!(retry_after > Duration::from_secs(50));
+            assert!(retry_after <= Duration::from_secs(60));
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limit_wait() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body("[]")
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .rate_limit(RateLimit::new(1, Duration::from_millis(100)).burst(1))
+        .build()?;
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        assert_eq!(client.trains().await?.len(), 0);
+    }
+
+    // The first request is sent immediately, the next two have to wait
+    assert!(start.elapsed() >= Duration::from_millis(190));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
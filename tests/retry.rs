@@ -0,0 +1,162 @@
+#![cfg(feature = "reqwest")]
+use std::time::Duration;
+
+use amtrak_api::{Client, Error, RetryPolicy};
+use mockito::Server;
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .max_attempts(3)
+        .base_delay(Duration::from_millis(10))
+        .max_delay(Duration::from_millis(100))
+        .jitter(0.0)
+}
+
+#[tokio::test]
+async fn test_retry_server_error() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let failed_mock = server
+        .mock("GET", "/stations")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let success_mock = server
+        .mock("GET", "/stations")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .retry_policy(retry_policy())
+        .build()?;
+
+    assert_eq!(client.stations().await?.len(), 0);
+
+    failed_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_gives_up_after_max_attempts() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_status(500)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .retry_policy(retry_policy())
+        .build()?;
+
+    let error = client.trains().await.unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 500));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_honors_retry_after() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let failed_mock = server
+        .mock("GET", "/trains/612-5")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let success_mock = server
+        .mock("GET", "/trains/612-5")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .retry_policy(retry_policy())
+        .build()?;
+
+    assert_eq!(client.train("612-5").await?.len(), 0);
+
+    failed_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_after_exceeds_max_delay() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_status(429)
+        .with_header("Retry-After", "3600")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .retry_policy(retry_policy())
+        .build()?;
+
+    let error = client.trains().await.unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 429));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_on_client_error() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations/ABC")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::builder()
+        .base_url(server.url().as_str())
+        .retry_policy(retry_policy())
+        .build()?;
+
+    let error = client.station("ABC").await.unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 404));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_retry_by_default() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/stations")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    let error = client.stations().await.unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 503));
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
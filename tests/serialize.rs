@@ -0,0 +1,145 @@
+mod common;
+
+use amtrak_api::{Clean, Station, Train, TrainStation};
+use common::Fixture;
+use serde_json::{json, Value};
+
+/// The fields of the train that differ from the fixture
+fn train_fields() -> Value {
+    json!({
+        "trainTimely": "NaN Minutes Early",
+        "stations": [
+            {
+                "name": "Middletown",
+                "code": "MID",
+                "tz": "America/New_York",
+                "bus": false,
+                "schArr": "2023-08-29T23:42:00-04:00",
+                "schDep": "2023-08-29T23:42:00-04:00",
+                "arr": "2023-08-29T23:42:00-04:00",
+                "dep": "2023-08-29T23:42:00-04:00",
+                "arrCmnt": "NaN Minutes Early",
+                "depCmnt": "NaN Minutes Early",
+                "status": "Enroute"
+            },
+            {
+                "name": "Harrisburg",
+                "code": "HAR",
+                "tz": "America/New_York",
+                "bus": false,
+                "schArr": "2023-08-29T23:56:00-04:00",
+                "schDep": "2023-08-29T23:56:00-04:00",
+                "arr": null,
+                "dep": null,
+                "arrCmnt": "NaN Minutes Early",
+                "depCmnt": "NaN Minutes Early",
+                "status": "Station"
+            }
+        ]
+    })
+}
+
+#[test]
+fn test_train_round_trip() -> serde_json::Result<()> {
+    let original = Train::fixture_json(train_fields());
+    let train: Train = serde_json::from_value(original.clone())?;
+
+    // Serializing produces the exact same shape as the Amtrak API
+    let serialized = serde_json::to_value(&train)?;
+    assert_eq!(serialized, original);
+
+    let deserialized: Train = serde_json::from_value(serialized)?;
+    assert_eq!(deserialized, train);
+
+    Ok(())
+}
+
+#[test]
+fn test_station_round_trip() -> serde_json::Result<()> {
+    let original = Station::fixture_json(json!({ "trains": ["657-30"] }));
+    let station: Station = serde_json::from_value(original.clone())?;
+
+    let serialized = serde_json::to_value(&station)?;
+    assert_eq!(serialized, original);
+
+    let deserialized: Station = serde_json::from_value(serialized)?;
+    assert_eq!(deserialized, station);
+
+    Ok(())
+}
+
+#[test]
+fn test_round_trip_normalizes_fields() -> serde_json::Result<()> {
+    // Integer zip codes are serialized as strings
+    let station = Station::fixture(json!({ "zip": 21001 }));
+    let serialized = serde_json::to_value(&station)?;
+    assert_eq!(serialized, Station::fixture_json(json!({ "zip": "21001" })));
+    assert_eq!(serde_json::from_value::<Station>(serialized)?, station);
+
+    // Missing arrival and departure times are serialized as null
+    let station = TrainStation::fixture(json!({}));
+    let serialized = serde_json::to_value(&station)?;
+    assert_eq!(
+        serialized,
+        TrainStation::fixture_json(json!({ "arr": null, "dep": null }))
+    );
+    assert_eq!(serde_json::from_value::<TrainStation>(serialized)?, station);
+
+    Ok(())
+}
+
+#[test]
+fn test_clean_train() -> serde_json::Result<()> {
+    let train = Train::fixture(train_fields());
+    let clean = serde_json::to_value(Clean(&train))?;
+
+    assert_eq!(clean["route_name"], "Keystone");
+    assert_eq!(clean["train_id"], "657-30");
+    assert_eq!(clean["origin_code"], "NYP");
+    assert_eq!(clean["last_value"], "2023-08-29T23:39:34-04:00");
+    assert_eq!(clean["object_id"], 847);
+    assert_eq!(clean["train_state"], "Active");
+    assert_eq!(
+        clean["stations"][0]["schedule_arrival"],
+        "2023-08-29T23:42:00-04:00"
+    );
+    assert_eq!(clean["stations"][0]["arrival_comment"], "NaN Minutes Early");
+    assert_eq!(clean["stations"][1]["arrival"], Value::Null);
+    assert!(clean.get("routeName").is_none());
+    assert!(clean["stations"][0].get("schArr").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_clean_station() -> serde_json::Result<()> {
+    let station = Station::fixture(json!({}));
+    let clean = serde_json::to_value(Clean(&station))?;
+
+    // Station fields already use the same names as the Amtrak API
+    assert_eq!(clean, serde_json::to_value(&station)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_clean_emits_every_field() -> serde_json::Result<()> {
+    let keys = |value: &Value| value.as_object().map(|object| object.len());
+
+    let train = Train::fixture(train_fields());
+    let serialized = serde_json::to_value(&train)?;
+    let clean = serde_json::to_value(Clean(&train))?;
+    assert_eq!(keys(&clean), keys(&serialized));
+    assert_eq!(
+        keys(&clean["stations"][0]),
+        keys(&serialized["stations"][0])
+    );
+
+    let station = Station::fixture(json!({}));
+    assert_eq!(
+        keys(&serde_json::to_value(Clean(&station))?),
+        keys(&serde_json::to_value(&station)?)
+    );
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+#![cfg(feature = "reqwest")]
 use amtrak_api::Client;
 use mockito::Server;
 
@@ -0,0 +1,91 @@
+use amtrak_api::{Timeliness, TimelinessComment};
+use chrono::Duration;
+
+#[test]
+fn test_parse_timeliness() {
+    assert_eq!(
+        Timeliness::parse("19 Minutes Late"),
+        Timeliness::Late(Duration::minutes(19))
+    );
+    assert_eq!(
+        Timeliness::parse("1 Hours, 5 Minutes Late"),
+        Timeliness::Late(Duration::minutes(65))
+    );
+    assert_eq!(
+        Timeliness::parse("1 Hour, 1 Minute Early"),
+        Timeliness::Early(Duration::minutes(61))
+    );
+    assert_eq!(
+        Timeliness::parse("3 Minutes Early"),
+        Timeliness::Early(Duration::minutes(3))
+    );
+    assert_eq!(Timeliness::parse("On Time"), Timeliness::OnTime);
+    assert_eq!(Timeliness::parse("0 Minutes Early"), Timeliness::OnTime);
+}
+
+#[test]
+fn test_parse_unknown_timeliness() {
+    assert_eq!(Timeliness::parse("NaN Minutes Early"), Timeliness::Unknown);
+    assert_eq!(Timeliness::parse("Unknown"), Timeliness::Unknown);
+    assert_eq!(Timeliness::parse(""), Timeliness::Unknown);
+    assert_eq!(Timeliness::parse("Late"), Timeliness::Unknown);
+    assert_eq!(Timeliness::parse("5 Fortnights Late"), Timeliness::Unknown);
+}
+
+#[test]
+fn test_parse_overflowing_timeliness() {
+    assert_eq!(
+        Timeliness::parse("9223372036854775807 Minutes Late"),
+        Timeliness::Unknown
+    );
+    assert_eq!(
+        Timeliness::parse("999999999999999 Hours Late"),
+        Timeliness::Unknown
+    );
+    assert_eq!(
+        Timeliness::parse("106751991167 Days, 8 Hours Early"),
+        Timeliness::Unknown
+    );
+}
+
+#[test]
+fn test_timeliness_delay() {
+    assert_eq!(
+        Timeliness::Late(Duration::minutes(5)).delay(),
+        Some(Duration::minutes(5))
+    );
+    assert_eq!(
+        Timeliness::Early(Duration::minutes(5)).delay(),
+        Some(Duration::minutes(-5))
+    );
+    assert_eq!(Timeliness::OnTime.delay(), Some(Duration::zero()));
+    assert_eq!(Timeliness::Unknown.delay(), None);
+
+    let mut comments: Vec<TimelinessComment> = ["19 Minutes Late", "On Time", "2 Minutes Early"]
+        .into_iter()
+        .map(TimelinessComment::new)
+        .collect();
+    comments.sort_by_key(TimelinessComment::delay);
+
+    assert_eq!(comments[0], "2 Minutes Early");
+    assert_eq!(comments[1], "On Time");
+    assert_eq!(comments[2], "19 Minutes Late");
+}
+
+#[test]
+fn test_timeliness_comment_round_trip() -> Result<(), serde_json::Error> {
+    let comment: TimelinessComment = serde_json::from_str("\"1 Hours, 5 Minutes Late\"")?;
+    assert_eq!(comment.as_str(), "1 Hours, 5 Minutes Late");
+    assert_eq!(
+        comment.timeliness(),
+        Timeliness::Late(Duration::minutes(65))
+    );
+    assert!(comment.timeliness().is_late());
+    assert_eq!(comment.to_string(), "1 Hours, 5 Minutes Late");
+    assert_eq!(
+        serde_json::to_string(&comment)?,
+        "\"1 Hours, 5 Minutes Late\""
+    );
+
+    Ok(())
+}
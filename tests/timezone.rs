@@ -0,0 +1,78 @@
+#![cfg(feature = "chrono-tz")]
+mod common;
+
+use amtrak_api::{Station, TrainStation};
+use chrono::{FixedOffset, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use common::Fixture;
+use serde_json::json;
+
+fn overnight_station(tz: Option<&str>) -> TrainStation {
+    // The train arrives before and departs after the end of daylight saving
+    // time on 2023-11-05 in America/New_York
+    TrainStation::fixture(json!({
+        "code": "PHL",
+        "tz": tz,
+        "schArr": "2023-11-05T01:30:00-04:00",
+        "schDep": "2023-11-05T01:10:00-05:00",
+        "arr": "2023-11-05T01:45:00-04:00",
+        "arrCmnt": "15 Minutes Late"
+    }))
+}
+
+#[test]
+fn test_station_local_times_across_dst() {
+    let station = overnight_station(Some("America/New_York"));
+    assert_eq!(station.timezone(), Some(Tz::America__New_York));
+
+    let times = station.local_times().unwrap();
+    assert_eq!(times.schedule_arrival.hour(), 1);
+    assert_eq!(times.schedule_arrival.minute(), 30);
+    assert_eq!(times.schedule_arrival.format("%Z").to_string(), "EDT");
+    assert_eq!(times.schedule_departure.hour(), 1);
+    assert_eq!(times.schedule_departure.minute(), 10);
+    assert_eq!(times.schedule_departure.format("%Z").to_string(), "EST");
+    assert_eq!(
+        times.arrival.unwrap().format("%H:%M %Z").to_string(),
+        "01:45 EDT"
+    );
+    assert!(times.departure.is_none());
+
+    // Converting only changes the representation of the instant
+    assert_eq!(times.schedule_arrival, station.schedule_arrival);
+}
+
+#[test]
+fn test_station_times_in_provided_zone() {
+    let station = overnight_station(Some("America/New_York"));
+
+    let chicago = station.times_in(&Tz::America__Chicago);
+    assert_eq!(
+        chicago.schedule_arrival.format("%H:%M %Z").to_string(),
+        "00:30 CDT"
+    );
+    assert_eq!(
+        chicago.schedule_departure.format("%H:%M %Z").to_string(),
+        "01:10 CDT"
+    );
+
+    let utc = station.times_in(&Utc);
+    assert_eq!(
+        utc.schedule_arrival,
+        Utc.with_ymd_and_hms(2023, 11, 5, 5, 30, 0).unwrap()
+    );
+
+    let fixed = station.times_in(&FixedOffset::west_opt(7 * 3600).unwrap());
+    assert_eq!(fixed.schedule_arrival.hour(), 22);
+}
+
+#[test]
+fn test_unknown_timezone() {
+    assert!(overnight_station(None).local_times().is_none());
+    assert!(overnight_station(Some("Mars/Olympus_Mons"))
+        .local_times()
+        .is_none());
+
+    let station = Station::fixture(json!({ "code": "CHI", "tz": "America/Chicago" }));
+    assert_eq!(station.timezone(), Some(Tz::America__Chicago));
+}
@@ -1,6 +1,11 @@
-use amtrak_api::{Client, TrainStatus};
+#![cfg(feature = "reqwest")]
+mod common;
+
+use amtrak_api::{Client, Provider, Timeliness, Train, TrainId, TrainStatus};
 use chrono::{FixedOffset, NaiveDate};
+use common::Fixture;
 use mockito::Server;
+use serde_json::json;
 
 #[tokio::test]
 async fn test_single_train() -> Result<(), amtrak_api::Error> {
@@ -272,13 +277,15 @@ async fn test_single_train() -> Result<(), amtrak_api::Error> {
 
     assert_eq!(trains.len(), 1);
 
-    let train = trains.get(0).unwrap();
+    let train = trains.first().unwrap();
 
     assert_eq!(train.route_name, "Keystone");
     assert_eq!(train.train_num, "657");
+    assert_eq!(train.train_id, TrainId::new("657", 30));
     assert_eq!(train.lat, 40.14815944794739);
     assert_eq!(train.lon, -76.61796031144218);
     assert_eq!(train.train_timely, "NaN Minutes Early");
+    assert_eq!(train.train_timely.timeliness(), Timeliness::Unknown);
 
     assert_eq!(train.stations.len(), 17);
 
@@ -325,6 +332,10 @@ async fn test_single_train() -> Result<(), amtrak_api::Error> {
     });
     assert_eq!(train.stations[0].arrival_comment, "0 Minutes Early");
     assert_eq!(train.stations[0].departure_comment, "0 Minutes Early");
+    assert_eq!(
+        train.stations[0].departure_comment.timeliness(),
+        Timeliness::OnTime
+    );
     assert_eq!(train.stations[0].status, TrainStatus::Departed);
 
     mock_server.assert_async().await;
@@ -349,3 +360,99 @@ async fn test_empty_trains() -> Result<(), amtrak_api::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_train_by_id() -> Result<(), amtrak_api::Error> {
+    let mut server = Server::new_async().await;
+    let id_mock = server
+        .mock("GET", "/trains/657-30")
+        .with_body("[]")
+        .create_async()
+        .await;
+    let padded_mock = server
+        .mock("GET", "/trains/657-05")
+        .with_body("[]")
+        .create_async()
+        .await;
+    let number_mock = server
+        .mock("GET", "/trains/657")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = Client::with_base_url(server.url().as_str());
+
+    assert_eq!(client.train(TrainId::new("657", 30)).await?.len(), 0);
+    let padded: TrainId = "657-05".parse().unwrap();
+    assert_eq!(client.train(padded).await?.len(), 0);
+    assert_eq!(client.train("657").await?.len(), 0);
+
+    id_mock.assert_async().await;
+    padded_mock.assert_async().await;
+    number_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_trains_by_provider() -> Result<(), amtrak_api::Error> {
+    let body = json!({
+        "510": [Train::fixture_json(json!({ "trainNum": "510", "trainID": "510-1" }))],
+        "V71": [Train::fixture_json(json!({
+            "trainNum": "V71",
+            "trainID": "V71-1",
+            "provider": "Via"
+        }))],
+        "B1": [Train::fixture_json(json!({
+            "trainNum": "B1",
+            "trainID": "B1-1",
+            "provider": "Brightline"
+        }))],
+    });
+
+    let mut server = Server::new_async().await;
+    let mock_server = server
+        .mock("GET", "/trains")
+        .with_body(body.to_string())
+        .expect(3)
+        .create_async()
+        .await;
+    let client = Client::with_base_url(server.url().as_str());
+
+    let via = client.trains_by_provider(Provider::Via).await?;
+    assert_eq!(via.len(), 1);
+    assert_eq!(
+        via["V71"],
+        [Train::fixture(
+            json!({ "trainNum": "V71", "trainID": "V71-1", "provider": "Via" })
+        )]
+    );
+    let train = &via["V71"][0];
+    assert_eq!(train.provider, Provider::Via);
+    assert_eq!(train.object_id, Some(847));
+    assert_eq!(train.amtrak_object_id(), None);
+
+    let amtrak = client.trains_by_provider("Amtrak").await?;
+    assert_eq!(amtrak.len(), 1);
+    assert_eq!(amtrak["510"][0].amtrak_object_id(), Some(847));
+
+    let other = client.trains_by_provider("Metra").await?;
+    assert!(other.is_empty());
+
+    mock_server.assert_async().await;
+
+    Ok(())
+}
+
+#[test]
+fn test_provider_conversion() {
+    assert_eq!(Provider::from("Amtrak"), Provider::Amtrak);
+    assert_eq!(Provider::from("Via"), Provider::Via);
+    assert_eq!(Provider::from("Brightline"), Provider::Brightline);
+    assert_eq!(
+        Provider::from("Metra".to_string()),
+        Provider::Other("Metra".to_string())
+    );
+    assert_eq!(Provider::Other("Metra".to_string()).as_str(), "Metra");
+    assert_eq!(Provider::Via, "Via");
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use amtrak_api::{TrainId, TrainIdentifier};
+
+#[test]
+fn test_parse_train_id() {
+    let train_id: TrainId = "657-30".parse().unwrap();
+    assert_eq!(train_id.train_num(), "657");
+    assert_eq!(train_id.instance(), Some(30));
+    assert_eq!(train_id, TrainId::new("657", 30));
+    assert_eq!(train_id.to_string(), "657-30");
+
+    let padded: TrainId = "657-05".parse().unwrap();
+    assert_eq!(padded.instance(), Some(5));
+    assert_eq!(padded.to_string(), "657-05");
+    assert_ne!(padded, TrainId::new("657", 5));
+
+    assert!("657".parse::<TrainId>().is_err());
+    assert!("-30".parse::<TrainId>().is_err());
+    assert!("657-".parse::<TrainId>().is_err());
+    assert!("657-abc".parse::<TrainId>().is_err());
+}
+
+#[test]
+fn test_train_id_ordering() {
+    let mut train_ids: Vec<TrainId> = ["93-4", "6-4", "657-30", "657-3", "v71-16"]
+        .into_iter()
+        .map(|train_id| train_id.parse().unwrap())
+        .collect();
+    train_ids.sort();
+
+    let sorted: Vec<String> = train_ids.iter().map(ToString::to_string).collect();
+    assert_eq!(sorted, vec!["6-4", "93-4", "657-3", "657-30", "v71-16"]);
+
+    let unique: HashSet<TrainId> = train_ids.into_iter().collect();
+    assert_eq!(unique.len(), 5);
+}
+
+#[test]
+fn test_train_id_serde() -> serde_json::Result<()> {
+    let train_id: TrainId = serde_json::from_str(r#""612-5""#)?;
+    assert_eq!(train_id, TrainId::new("612", 5));
+    assert_eq!(serde_json::to_string(&train_id)?, r#""612-5""#);
+
+    let padded: TrainId = serde_json::from_str(r#""612-05""#)?;
+    assert_eq!(serde_json::to_string(&padded)?, r#""612-05""#);
+
+    // Identifiers without an instance are kept instead of failing
+    let bare: TrainId = serde_json::from_str(r#""612""#)?;
+    assert_eq!(bare.train_num(), "612");
+    assert_eq!(bare.instance(), None);
+    assert_eq!(serde_json::to_string(&bare)?, r#""612""#);
+
+    Ok(())
+}
+
+#[test]
+fn test_train_identifier() {
+    assert_eq!(
+        TrainIdentifier::from("612-5"),
+        TrainIdentifier::Id(TrainId::new("612", 5))
+    );
+    assert_eq!(
+        TrainIdentifier::from("612".to_string()),
+        TrainIdentifier::Number("612".to_string())
+    );
+    assert_eq!(
+        TrainIdentifier::from(&TrainId::new("612", 5)).to_string(),
+        "612-5"
+    );
+    assert_eq!(TrainIdentifier::from("612-05").to_string(), "612-05");
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use amtrak_api::{
+    transport::{BoxFuture, Transport, TransportError},
+    Client, Error, RateLimit, RetryPolicy,
+};
+
+type FakeResponse = Result<http::Response<Vec<u8>>, TransportError>;
+
+/// An in-memory transport returning queued responses and recording every
+/// requested url
+#[derive(Debug, Default, Clone)]
+struct FakeTransport {
+    responses: Arc<Mutex<VecDeque<FakeResponse>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+    sleeps: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl FakeTransport {
+    fn push_response(&self, status: u16, body: &str) {
+        let mut response = http::Response::new(body.as_bytes().to_vec());
+        *response.status_mut() = http::StatusCode::from_u16(status).unwrap();
+        self.responses.lock().unwrap().push_back(Ok(response));
+    }
+
+    fn push_error(&self, error: TransportError) {
+        self.responses.lock().unwrap().push_back(Err(error));
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.lock().unwrap().clone()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn get<'a>(&'a self, url: &'a str) -> BoxFuture<'a, FakeResponse> {
+        Box::pin(async move {
+            self.requests.lock().unwrap().push(url.to_string());
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("No response queued")
+        })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        // Record the delay instead of waiting so no timer (or runtime) is needed
+        self.sleeps.lock().unwrap().push(duration);
+        Box::pin(async {})
+    }
+}
+
+/// Polls the future to completion without any async runtime
+///
+/// Only suitable for futures that never return pending, such as the ones
+/// returned by the [`FakeTransport`].
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_custom_transport() -> Result<(), Error> {
+    let transport = FakeTransport::default();
+    transport.push_response(
+        200,
+        r#"
+{
+    "PHL": {
+        "name": "Philadelphia 30th Street",
+        "code": "PHL",
+        "tz": "America/New_York",
+        "lat": 39.955864,
+        "lon": -75.181887,
+        "address1": "2955 Market Street",
+        "address2": " ",
+        "city": "Philadelphia",
+        "state": "PA",
+        "zip": 19104,
+        "trains": ["612-5"]
+    }
+}"#,
+    );
+
+    let client = Client::builder()
+        .base_url("http://amtrak.test")
+        .transport(transport.clone())
+        .build()?;
+
+    let response = client.station("PHL").await?;
+    let station = response.get("PHL").unwrap();
+    assert_eq!(station.name, "Philadelphia 30th Street");
+    assert_eq!(station.zip, "19104");
+
+    assert_eq!(
+        transport.requests(),
+        vec!["http://amtrak.test/stations/PHL"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_error_response() -> Result<(), Error> {
+    let transport = FakeTransport::default();
+    transport.push_response(500, "Internal Server Error");
+
+    let client = Client::builder().transport(transport.clone()).build()?;
+
+    let error = client.trains().await.unwrap_err();
+    assert!(matches!(error, Error::ApiErrorResponse(response) if response.status == 500));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_retry_connect_error() -> Result<(), Error> {
+    let transport = FakeTransport::default();
+    transport.push_error(TransportError::connect("connection refused"));
+    transport.push_error(TransportError::timeout("timed out"));
+    transport.push_response(200, "[]");
+
+    let client = Client::builder()
+        .transport(transport.clone())
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(3)
+                .base_delay(Duration::from_millis(1)),
+        )
+        .build()?;
+
+    assert!(client.trains().await?.is_empty());
+    assert_eq!(transport.requests().len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_other_error_not_retried() -> Result<(), Error> {
+    let transport = FakeTransport::default();
+    transport.push_error(TransportError::other("invalid url"));
+
+    let client = Client::builder()
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy::new().base_delay(Duration::from_millis(1)))
+        .build()?;
+
+    let error = client.stations().await.unwrap_err();
+    assert!(matches!(error, Error::RequestFailed(error) if !error.is_connect()));
+    assert_eq!(transport.requests().len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_transport_sleep_without_runtime() -> Result<(), Error> {
+    let transport = FakeTransport::default();
+    transport.push_error(TransportError::connect("connection refused"));
+    transport.push_response(200, "[]");
+    transport.push_response(200, "[]");
+
+    let client = Client::builder()
+        .transport(transport.clone())
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(2)
+                .base_delay(Duration::from_secs(1))
+                .jitter(0.0),
+        )
+        .rate_limit(RateLimit::new(1, Duration::from_secs(60)).burst(2))
+        .build()?;
+
+    // The retry waits for the backoff delay and the third request waits for
+    // the rate limit, both through `Transport::sleep`
+    assert!(block_on(client.trains())?.is_empty());
+    assert!(block_on(client.stations())?.is_empty());
+    assert_eq!(transport.requests().len(), 3);
+
+    let sleeps = transport.sleeps();
+    assert_eq!(sleeps.len(), 2);
+    assert_eq!(sleeps[0], Duration::from_secs(1));
+    assert!(sleeps[1] > Duration::from_secs(59));
+
+    Ok(())
+}